@@ -1,11 +1,25 @@
 use core::fmt;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Gender {
     #[default]
     Male = 0,
     Female = 1,
+    /// Only used by Pokemon, trainers are always male or female.
+    Genderless = 2,
+}
+
+impl Gender {
+    /// Returns the compact symbol of the gender, `-` for genderless.
+    pub const fn symbol(&self) -> char {
+        match self {
+            Gender::Male => '♂',
+            Gender::Female => '♀',
+            Gender::Genderless => '-',
+        }
+    }
 }
 
 impl fmt::Display for Gender {
@@ -13,6 +27,234 @@ impl fmt::Display for Gender {
         match self {
             Gender::Male => write!(f, "Male"),
             Gender::Female => write!(f, "Female"),
+            Gender::Genderless => write!(f, "Genderless"),
+        }
+    }
+}
+
+/// A stat, in the order the games store IVs and EVs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum StatKind {
+    Hp = 0,
+    Attack = 1,
+    Defense = 2,
+    Speed = 3,
+    SpecialAttack = 4,
+    SpecialDefense = 5,
+}
+
+impl StatKind {
+    pub const ALL: [Self; 6] = [
+        Self::Hp,
+        Self::Attack,
+        Self::Defense,
+        Self::Speed,
+        Self::SpecialAttack,
+        Self::SpecialDefense,
+    ];
+}
+
+/// The type of a Pokemon or move, Generation 3 predates the Fairy type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    Normal,
+    Fighting,
+    Flying,
+    Poison,
+    Ground,
+    Rock,
+    Bug,
+    Ghost,
+    Steel,
+    Fire,
+    Water,
+    Grass,
+    Electric,
+    Psychic,
+    Ice,
+    Dragon,
+    Dark,
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// A nature raises one stat by 10% and lowers another one by 10%, natures that would raise and
+/// lower the same stat are neutral. HP is never affected.
+///
+/// Natures are numbered so that the raised stat is `1 + nature / 5` and the lowered stat is
+/// `1 + nature % 5`, using the order of [`StatKind`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Nature {
+    #[default]
+    Hardy = 0,
+    Lonely = 1,
+    Brave = 2,
+    Adamant = 3,
+    Naughty = 4,
+    Bold = 5,
+    Docile = 6,
+    Relaxed = 7,
+    Impish = 8,
+    Lax = 9,
+    Timid = 10,
+    Hasty = 11,
+    Serious = 12,
+    Jolly = 13,
+    Naive = 14,
+    Modest = 15,
+    Mild = 16,
+    Quiet = 17,
+    Bashful = 18,
+    Rash = 19,
+    Calm = 20,
+    Gentle = 21,
+    Sassy = 22,
+    Careful = 23,
+    Quirky = 24,
+}
+
+impl Nature {
+    pub const ALL: [Self; 25] = [
+        Self::Hardy,
+        Self::Lonely,
+        Self::Brave,
+        Self::Adamant,
+        Self::Naughty,
+        Self::Bold,
+        Self::Docile,
+        Self::Relaxed,
+        Self::Impish,
+        Self::Lax,
+        Self::Timid,
+        Self::Hasty,
+        Self::Serious,
+        Self::Jolly,
+        Self::Naive,
+        Self::Modest,
+        Self::Mild,
+        Self::Quiet,
+        Self::Bashful,
+        Self::Rash,
+        Self::Calm,
+        Self::Gentle,
+        Self::Sassy,
+        Self::Careful,
+        Self::Quirky,
+    ];
+
+    /// Returns the nature with the given index (0 to 24).
+    pub const fn from_index(index: u8) -> Option<Self> {
+        if index < Self::ALL.len() as u8 {
+            Some(Self::ALL[index as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the nature determined by a personality value.
+    pub const fn from_personality(personality: u32) -> Self {
+        Self::ALL[(personality % 25) as usize]
+    }
+
+    /// Returns the stat raised by 10%, or `None` for neutral natures.
+    pub const fn raised_stat(self) -> Option<StatKind> {
+        if self.is_neutral() {
+            None
+        } else {
+            Some(StatKind::ALL[1 + self as usize / 5])
+        }
+    }
+
+    /// Returns the stat lowered by 10%, or `None` for neutral natures.
+    pub const fn lowered_stat(self) -> Option<StatKind> {
+        if self.is_neutral() {
+            None
+        } else {
+            Some(StatKind::ALL[1 + self as usize % 5])
+        }
+    }
+
+    pub const fn is_neutral(self) -> bool {
+        self as u8 / 5 == self as u8 % 5
+    }
+
+    /// Returns the multiplier applied to `stat`: 1.1, 0.9 or 1.0.
+    pub fn stat_modifier(self, stat: StatKind) -> f32 {
+        if self.raised_stat() == Some(stat) {
+            1.1
+        } else if self.lowered_stat() == Some(stat) {
+            0.9
+        } else {
+            1.0
+        }
+    }
+}
+
+impl fmt::Display for Nature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nature_modifiers() {
+        use StatKind::*;
+        let table = [
+            (Nature::Hardy, None),
+            (Nature::Lonely, Some((Attack, Defense))),
+            (Nature::Brave, Some((Attack, Speed))),
+            (Nature::Adamant, Some((Attack, SpecialAttack))),
+            (Nature::Naughty, Some((Attack, SpecialDefense))),
+            (Nature::Bold, Some((Defense, Attack))),
+            (Nature::Docile, None),
+            (Nature::Relaxed, Some((Defense, Speed))),
+            (Nature::Impish, Some((Defense, SpecialAttack))),
+            (Nature::Lax, Some((Defense, SpecialDefense))),
+            (Nature::Timid, Some((Speed, Attack))),
+            (Nature::Hasty, Some((Speed, Defense))),
+            (Nature::Serious, None),
+            (Nature::Jolly, Some((Speed, SpecialAttack))),
+            (Nature::Naive, Some((Speed, SpecialDefense))),
+            (Nature::Modest, Some((SpecialAttack, Attack))),
+            (Nature::Mild, Some((SpecialAttack, Defense))),
+            (Nature::Quiet, Some((SpecialAttack, Speed))),
+            (Nature::Bashful, None),
+            (Nature::Rash, Some((SpecialAttack, SpecialDefense))),
+            (Nature::Calm, Some((SpecialDefense, Attack))),
+            (Nature::Gentle, Some((SpecialDefense, Defense))),
+            (Nature::Sassy, Some((SpecialDefense, Speed))),
+            (Nature::Careful, Some((SpecialDefense, SpecialAttack))),
+            (Nature::Quirky, None),
+        ];
+
+        for (index, (nature, modified)) in table.into_iter().enumerate() {
+            assert_eq!(Some(nature), Nature::from_index(index as u8));
+            assert_eq!(nature, Nature::from_personality(25 * 1000 + index as u32));
+            assert_eq!(modified.is_none(), nature.is_neutral(), "{nature}");
+            assert_eq!(modified.map(|(raised, _)| raised), nature.raised_stat());
+            assert_eq!(modified.map(|(_, lowered)| lowered), nature.lowered_stat());
+            for stat in StatKind::ALL {
+                let expected = match modified {
+                    Some((raised, _)) if raised == stat => 1.1,
+                    Some((_, lowered)) if lowered == stat => 0.9,
+                    _ => 1.0,
+                };
+                assert_eq!(expected, nature.stat_modifier(stat), "{nature} {stat:?}");
+            }
         }
+        assert_eq!(None, Nature::from_index(25));
     }
 }
@@ -1,12 +1,23 @@
 use core::fmt;
 
+use crate::PkResult;
+
 #[derive(Debug)]
 pub enum PkError {
     Load(PkErrorLoad),
     InvalidData(&'static str),
     NotAvailableInGameVersion(&'static str),
+    IndexOutOfBounds {
+        name: &'static str,
+        index: usize,
+        len: usize,
+    },
     Msg(&'static str),
     Io(std::io::Error),
+    Context {
+        source: Box<PkError>,
+        context: String,
+    },
 }
 
 impl fmt::Display for PkError {
@@ -18,8 +29,15 @@ impl fmt::Display for PkError {
                 f,
                 "the requested datum \"{m}\" is not available in the version of the loaded game"
             ),
+            PkError::IndexOutOfBounds { name, index, len } => {
+                write!(
+                    f,
+                    "{name} index {index} is out of bounds, expected less than {len}"
+                )
+            }
             PkError::Msg(m) => write!(f, "{m}"),
             PkError::Io(e) => write!(f, "io: {e}"),
+            PkError::Context { context, .. } => write!(f, "{context}"),
         }
     }
 }
@@ -30,7 +48,28 @@ impl From<std::io::Error> for PkError {
     }
 }
 
-impl std::error::Error for PkError {}
+impl std::error::Error for PkError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PkError::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Adds context to the error of a [`PkResult`], keeping the original error as its source.
+pub trait PkResultExt<T> {
+    fn context(self, context: impl Into<String>) -> PkResult<T>;
+}
+
+impl<T> PkResultExt<T> for PkResult<T> {
+    fn context(self, context: impl Into<String>) -> PkResult<T> {
+        self.map_err(|source| PkError::Context {
+            source: Box::new(source),
+            context: context.into(),
+        })
+    }
+}
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum PkErrorLoad {
@@ -50,7 +89,7 @@ pub enum PkErrorLoad {
     },
     MissingSection(&'static str),
     InvalidSectionId(u16),
-    MissmatchedSaveFileIndex(u32, u32),
+    MismatchedSaveFileIndex(u32, u32),
 }
 
 impl fmt::Display for PkErrorLoad {
@@ -59,14 +98,74 @@ impl fmt::Display for PkErrorLoad {
             PkErrorLoad::SaveFileTooSmall {
                 expected_size,
                 received_size,
-            } => write!(f, "expected save file with a minimum size of {expected_size} bytes, received file with {received_size} bytes"),
-            PkErrorLoad::InvalidChecksum { section_id, expected, found } => write!(f, "section {section_id} has an invalid checksum {found}, expected {expected}"),
-            PkErrorLoad::InvalidSignature { section_id, expected, found } => write!(f, "section {section_id} has an invalid signature {found}, expected {expected}"),
-            PkErrorLoad::MissingSection(section_name) => write!(f, "save file missing section: {section_name}"),
-            PkErrorLoad::InvalidSectionId(id) => write!(f, "save file contains invalid section id: {id}"),
-            PkErrorLoad::MissmatchedSaveFileIndex(first, second) => write!(f, "sections contain missmatching save indices: {first} - {second}"),
+            } => write!(
+                f,
+                "save file is too small: received {received_size} bytes, but saves are {} KiB minimum ({expected_size} bytes)",
+                expected_size / 1024
+            ),
+            PkErrorLoad::InvalidChecksum {
+                section_id,
+                expected,
+                found,
+            } => write!(
+                f,
+                "section {section_id}: checksum mismatch (got {found:#06X}, expected {expected:#06X})"
+            ),
+            PkErrorLoad::InvalidSignature {
+                section_id,
+                expected,
+                found,
+            } => write!(
+                f,
+                "section {section_id}: missing magic signature (got {found:#010X}, expected {expected:#010X}), the section may be corrupted or not part of a save file"
+            ),
+            PkErrorLoad::MissingSection(section_name) => {
+                write!(f, "save file is missing the {section_name} section")
+            }
+            PkErrorLoad::InvalidSectionId(id) => {
+                write!(f, "save file contains an invalid section id: {id}")
+            }
+            PkErrorLoad::MismatchedSaveFileIndex(expected, found) => write!(
+                f,
+                "sections have mismatched save indices: expected {expected}, found {found}"
+            ),
         }
     }
 }
 
 impl std::error::Error for PkErrorLoad {}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn context() {
+        let result: PkResult<()> = Err(PkError::Msg("inner"));
+        let error = result.context("outer").unwrap_err();
+
+        assert_eq!("outer", error.to_string());
+        assert_eq!("inner", error.source().unwrap().to_string());
+    }
+
+    #[test]
+    fn load_messages() {
+        let checksum = PkErrorLoad::InvalidChecksum {
+            section_id: 3,
+            expected: 0xBEEF,
+            found: 0x12,
+        };
+        assert_eq!(
+            "section 3: checksum mismatch (got 0x0012, expected 0xBEEF)",
+            checksum.to_string()
+        );
+
+        let too_small = PkErrorLoad::SaveFileTooSmall {
+            expected_size: 128 * 1024,
+            received_size: 64,
+        };
+        assert!(too_small.to_string().contains("128 KiB minimum"));
+    }
+}
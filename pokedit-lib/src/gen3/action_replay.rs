@@ -0,0 +1,93 @@
+use crate::{error::PkError, PkResult};
+
+use super::{Game, GameVersion, TrainerSection};
+
+/// Parses a raw (decrypted) Action Replay code, made up of pairs of words written as 8 hex digits
+/// (`0AAAAAAA 000000VV`), each pair writing the byte `VV` to the address `AAAAAAA`.
+///
+/// Only 8-bit writes are supported, every other code type (e.g. ROM patches or execution hooks)
+/// is rejected.
+pub fn parse_action_replay_code(code: &str) -> PkResult<Vec<(u32, u8)>> {
+    let words = code
+        .split_whitespace()
+        .map(|word| {
+            if word.len() != 8 || !word.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(PkError::Msg(
+                    "action replay codes are made up of 8 hex digit words",
+                ));
+            }
+            Ok(u32::from_str_radix(word, 16).unwrap())
+        })
+        .collect::<PkResult<Vec<_>>>()?;
+
+    if words.is_empty() || words.len() % 2 != 0 {
+        return Err(PkError::Msg(
+            "action replay codes are made up of address/value pairs",
+        ));
+    }
+
+    words
+        .chunks_exact(2)
+        .map(|pair| {
+            let (address, value) = (pair[0], pair[1]);
+            if address >> 28 != 0 {
+                return Err(PkError::Msg(
+                    "only 8-bit write action replay codes are supported",
+                ));
+            }
+            let value = u8::try_from(value)
+                .map_err(|_| PkError::Msg("8-bit write action replay codes write a single byte"))?;
+            Ok((address, value))
+        })
+        .collect()
+}
+
+impl<'d> Game<'d> {
+    /// Ruby/Sapphire keep the save blocks at fixed addresses, unlike Emerald and FireRed/LeafGreen
+    /// which move them around at runtime.
+    const RS_SAVE_BLOCK1_ADDRESS: u32 = 0x0202_5734;
+    const RS_SAVE_BLOCK2_ADDRESS: u32 = 0x0202_4EA4;
+    const RS_SAVE_BLOCK2_SIZE: usize = 0x0890;
+
+    /// Applies a raw Action Replay code (see [`parse_action_replay_code`]) to the save file by
+    /// mapping the RAM addresses to their location in the save data. Nothing is written if any of
+    /// the addresses falls outside the save data.
+    ///
+    /// Only available for Ruby/Sapphire.
+    pub fn apply_action_replay_code(&mut self, code: &str) -> PkResult<()> {
+        if self.version != GameVersion::RubySapphire {
+            return Err(PkError::NotAvailableInGameVersion("Action Replay codes"));
+        }
+
+        let writes = parse_action_replay_code(code)?
+            .into_iter()
+            .map(|(address, value)| Ok((self.ram_address_offset(address)?, value)))
+            .collect::<PkResult<Vec<_>>>()?;
+
+        for (offset, value) in writes {
+            self.data[offset] = value;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the offset into the save file of a RAM address in Ruby/Sapphire.
+    fn ram_address_offset(&self, address: u32) -> PkResult<usize> {
+        let save_block1_offset = address.wrapping_sub(Self::RS_SAVE_BLOCK1_ADDRESS) as usize;
+        if save_block1_offset < Self::SAVE_BLOCK1_SIZE {
+            return Ok(self.save_block1_offset(save_block1_offset));
+        }
+
+        let save_block2_offset = address.wrapping_sub(Self::RS_SAVE_BLOCK2_ADDRESS) as usize;
+        if save_block2_offset < Self::RS_SAVE_BLOCK2_SIZE {
+            return Ok(self
+                .current_save_slot_info
+                .section_offset(TrainerSection::ID)
+                + save_block2_offset);
+        }
+
+        Err(PkError::Msg(
+            "the action replay code writes outside of the save data",
+        ))
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+
+use crate::PkResult;
+
+use super::{Data, Game, PartyPokemonData, Playtime, PocketKind, TeamItemsSection};
+
+/// A difference between two saves, see [`Game::diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SaveChange {
+    Money {
+        before: u32,
+        after: u32,
+    },
+    Badges {
+        before: u8,
+        after: u8,
+    },
+    TimePlayed {
+        before: Playtime,
+        after: Playtime,
+    },
+    /// The Pokemon in the party slot was modified, replaced, added or removed. The species is 0
+    /// for empty slots.
+    PartyPokemon {
+        slot: usize,
+        before: u16,
+        after: u16,
+    },
+    /// The quantity of an item changed, it was added if `before` is 0 and removed if `after` is 0.
+    Item {
+        pocket: PocketKind,
+        id: u16,
+        before: u16,
+        after: u16,
+    },
+}
+
+impl Game<'_> {
+    /// Returns what changed from this save to `other`: money, badges, time played, party Pokemon
+    /// and bag items.
+    pub fn diff(&self, other: &Game) -> PkResult<Vec<SaveChange>> {
+        let mut changes = vec![];
+        let (before, after) = (self.team_items(), other.team_items());
+
+        if before.money() != after.money() {
+            changes.push(SaveChange::Money {
+                before: before.money(),
+                after: after.money(),
+            });
+        }
+
+        let badges = (self.badges_earned_count()?, other.badges_earned_count()?);
+        if badges.0 != badges.1 {
+            changes.push(SaveChange::Badges {
+                before: badges.0,
+                after: badges.1,
+            });
+        }
+
+        let time_played = (self.trainer().time_played(), other.trainer().time_played());
+        if time_played.0 != time_played.1 {
+            changes.push(SaveChange::TimePlayed {
+                before: time_played.0,
+                after: time_played.1,
+            });
+        }
+
+        let party_before = before.party().collect::<Vec<_>>();
+        let party_after = after.party().collect::<Vec<_>>();
+        for slot in 0..TeamItemsSection::PARTY_SIZE {
+            let (before, after) = (party_before.get(slot), party_after.get(slot));
+            if before.map(|pokemon| pokemon.data) == after.map(|pokemon| pokemon.data) {
+                continue;
+            }
+            let species = |pokemon: Option<&Data<PartyPokemonData>>| {
+                pokemon.map_or(0, |pokemon| pokemon.pokemon().decrypt().species())
+            };
+            changes.push(SaveChange::PartyPokemon {
+                slot,
+                before: species(before),
+                after: species(after),
+            });
+        }
+
+        for pocket in PocketKind::ALL {
+            let mut items = BTreeMap::<u16, (u16, u16)>::new();
+            for item in before.pocket(pocket) {
+                items.entry(item.id).or_default().0 += item.quantity;
+            }
+            for item in after.pocket(pocket) {
+                items.entry(item.id).or_default().1 += item.quantity;
+            }
+            changes.extend(
+                items
+                    .into_iter()
+                    .filter(|(_, (before, after))| before != after)
+                    .map(|(id, (before, after))| SaveChange::Item {
+                        pocket,
+                        id,
+                        before,
+                        after,
+                    }),
+            );
+        }
+
+        Ok(changes)
+    }
+}
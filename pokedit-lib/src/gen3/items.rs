@@ -0,0 +1,1016 @@
+//! Item data, indexed by the item ID used by the games.
+//!
+//! Ruby and Sapphire know the items up to HM08. FireRed and LeafGreen add their key items after
+//! it, and Emerald knows every item, including two key items of its own.
+
+use crate::{error::PkError, PkResult};
+
+/// An item known to at least one of the Generation 3 games. IDs in between which don't belong to
+/// any item aren't represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum ItemId {
+    MasterBall = 1,
+    UltraBall = 2,
+    GreatBall = 3,
+    PokeBall = 4,
+    SafariBall = 5,
+    NetBall = 6,
+    DiveBall = 7,
+    NestBall = 8,
+    RepeatBall = 9,
+    TimerBall = 10,
+    LuxuryBall = 11,
+    PremierBall = 12,
+    Potion = 13,
+    Antidote = 14,
+    BurnHeal = 15,
+    IceHeal = 16,
+    Awakening = 17,
+    ParalyzeHeal = 18,
+    FullRestore = 19,
+    MaxPotion = 20,
+    HyperPotion = 21,
+    SuperPotion = 22,
+    FullHeal = 23,
+    Revive = 24,
+    MaxRevive = 25,
+    FreshWater = 26,
+    SodaPop = 27,
+    Lemonade = 28,
+    MoomooMilk = 29,
+    EnergyPowder = 30,
+    EnergyRoot = 31,
+    HealPowder = 32,
+    RevivalHerb = 33,
+    Ether = 34,
+    MaxEther = 35,
+    Elixir = 36,
+    MaxElixir = 37,
+    LavaCookie = 38,
+    BlueFlute = 39,
+    YellowFlute = 40,
+    RedFlute = 41,
+    BlackFlute = 42,
+    WhiteFlute = 43,
+    BerryJuice = 44,
+    SacredAsh = 45,
+    ShoalSalt = 46,
+    ShoalShell = 47,
+    RedShard = 48,
+    BlueShard = 49,
+    YellowShard = 50,
+    GreenShard = 51,
+    HpUp = 63,
+    Protein = 64,
+    Iron = 65,
+    Carbos = 66,
+    Calcium = 67,
+    RareCandy = 68,
+    PpUp = 69,
+    Zinc = 70,
+    PpMax = 71,
+    GuardSpec = 73,
+    DireHit = 74,
+    XAttack = 75,
+    XDefend = 76,
+    XSpeed = 77,
+    XAccuracy = 78,
+    XSpecial = 79,
+    PokeDoll = 80,
+    FluffyTail = 81,
+    SuperRepel = 83,
+    MaxRepel = 84,
+    EscapeRope = 85,
+    Repel = 86,
+    SunStone = 93,
+    MoonStone = 94,
+    FireStone = 95,
+    ThunderStone = 96,
+    WaterStone = 97,
+    LeafStone = 98,
+    TinyMushroom = 103,
+    BigMushroom = 104,
+    Pearl = 106,
+    BigPearl = 107,
+    Stardust = 108,
+    StarPiece = 109,
+    Nugget = 110,
+    HeartScale = 111,
+    OrangeMail = 121,
+    HarborMail = 122,
+    GlitterMail = 123,
+    MechMail = 124,
+    WoodMail = 125,
+    WaveMail = 126,
+    BeadMail = 127,
+    ShadowMail = 128,
+    TropicMail = 129,
+    DreamMail = 130,
+    FabMail = 131,
+    RetroMail = 132,
+    CheriBerry = 133,
+    ChestoBerry = 134,
+    PechaBerry = 135,
+    RawstBerry = 136,
+    AspearBerry = 137,
+    LeppaBerry = 138,
+    OranBerry = 139,
+    PersimBerry = 140,
+    LumBerry = 141,
+    SitrusBerry = 142,
+    FigyBerry = 143,
+    WikiBerry = 144,
+    MagoBerry = 145,
+    AguavBerry = 146,
+    IapapaBerry = 147,
+    RazzBerry = 148,
+    BlukBerry = 149,
+    NanabBerry = 150,
+    WepearBerry = 151,
+    PinapBerry = 152,
+    PomegBerry = 153,
+    KelpsyBerry = 154,
+    QualotBerry = 155,
+    HondewBerry = 156,
+    GrepaBerry = 157,
+    TamatoBerry = 158,
+    CornnBerry = 159,
+    MagostBerry = 160,
+    RabutaBerry = 161,
+    NomelBerry = 162,
+    SpelonBerry = 163,
+    PamtreBerry = 164,
+    WatmelBerry = 165,
+    DurinBerry = 166,
+    BelueBerry = 167,
+    LiechiBerry = 168,
+    GanlonBerry = 169,
+    SalacBerry = 170,
+    PetayaBerry = 171,
+    ApicotBerry = 172,
+    LansatBerry = 173,
+    StarfBerry = 174,
+    EnigmaBerry = 175,
+    BrightPowder = 179,
+    WhiteHerb = 180,
+    MachoBrace = 181,
+    ExpShare = 182,
+    QuickClaw = 183,
+    SootheBell = 184,
+    MentalHerb = 185,
+    ChoiceBand = 186,
+    KingsRock = 187,
+    SilverPowder = 188,
+    AmuletCoin = 189,
+    CleanseTag = 190,
+    SoulDew = 191,
+    DeepSeaTooth = 192,
+    DeepSeaScale = 193,
+    SmokeBall = 194,
+    Everstone = 195,
+    FocusBand = 196,
+    LuckyEgg = 197,
+    ScopeLens = 198,
+    MetalCoat = 199,
+    Leftovers = 200,
+    DragonScale = 201,
+    LightBall = 202,
+    SoftSand = 203,
+    HardStone = 204,
+    MiracleSeed = 205,
+    BlackGlasses = 206,
+    BlackBelt = 207,
+    Magnet = 208,
+    MysticWater = 209,
+    SharpBeak = 210,
+    PoisonBarb = 211,
+    NeverMeltIce = 212,
+    SpellTag = 213,
+    TwistedSpoon = 214,
+    Charcoal = 215,
+    DragonFang = 216,
+    SilkScarf = 217,
+    UpGrade = 218,
+    ShellBell = 219,
+    SeaIncense = 220,
+    LaxIncense = 221,
+    LuckyPunch = 222,
+    MetalPowder = 223,
+    ThickClub = 224,
+    Stick = 225,
+    RedScarf = 254,
+    BlueScarf = 255,
+    PinkScarf = 256,
+    GreenScarf = 257,
+    YellowScarf = 258,
+    MachBike = 259,
+    CoinCase = 260,
+    Itemfinder = 261,
+    OldRod = 262,
+    GoodRod = 263,
+    SuperRod = 264,
+    SsTicket = 265,
+    ContestPass = 266,
+    WailmerPail = 268,
+    DevonGoods = 269,
+    SootSack = 270,
+    BasementKey = 271,
+    AcroBike = 272,
+    PokeblockCase = 273,
+    Letter = 274,
+    EonTicket = 275,
+    RedOrb = 276,
+    BlueOrb = 277,
+    Scanner = 278,
+    GoGoggles = 279,
+    Meteorite = 280,
+    Room1Key = 281,
+    Room2Key = 282,
+    Room4Key = 283,
+    Room6Key = 284,
+    StorageKey = 285,
+    RootFossil = 286,
+    ClawFossil = 287,
+    DevonScope = 288,
+    Tm01 = 289,
+    Tm02 = 290,
+    Tm03 = 291,
+    Tm04 = 292,
+    Tm05 = 293,
+    Tm06 = 294,
+    Tm07 = 295,
+    Tm08 = 296,
+    Tm09 = 297,
+    Tm10 = 298,
+    Tm11 = 299,
+    Tm12 = 300,
+    Tm13 = 301,
+    Tm14 = 302,
+    Tm15 = 303,
+    Tm16 = 304,
+    Tm17 = 305,
+    Tm18 = 306,
+    Tm19 = 307,
+    Tm20 = 308,
+    Tm21 = 309,
+    Tm22 = 310,
+    Tm23 = 311,
+    Tm24 = 312,
+    Tm25 = 313,
+    Tm26 = 314,
+    Tm27 = 315,
+    Tm28 = 316,
+    Tm29 = 317,
+    Tm30 = 318,
+    Tm31 = 319,
+    Tm32 = 320,
+    Tm33 = 321,
+    Tm34 = 322,
+    Tm35 = 323,
+    Tm36 = 324,
+    Tm37 = 325,
+    Tm38 = 326,
+    Tm39 = 327,
+    Tm40 = 328,
+    Tm41 = 329,
+    Tm42 = 330,
+    Tm43 = 331,
+    Tm44 = 332,
+    Tm45 = 333,
+    Tm46 = 334,
+    Tm47 = 335,
+    Tm48 = 336,
+    Tm49 = 337,
+    Tm50 = 338,
+    Hm01 = 339,
+    Hm02 = 340,
+    Hm03 = 341,
+    Hm04 = 342,
+    Hm05 = 343,
+    Hm06 = 344,
+    Hm07 = 345,
+    Hm08 = 346,
+    /// FireRed, LeafGreen and Emerald only.
+    OaksParcel = 349,
+    /// FireRed, LeafGreen and Emerald only.
+    PokeFlute = 350,
+    /// FireRed, LeafGreen and Emerald only.
+    SecretKey = 351,
+    /// FireRed, LeafGreen and Emerald only.
+    BikeVoucher = 352,
+    /// FireRed, LeafGreen and Emerald only.
+    GoldTeeth = 353,
+    /// FireRed, LeafGreen and Emerald only.
+    OldAmber = 354,
+    /// FireRed, LeafGreen and Emerald only.
+    CardKey = 355,
+    /// FireRed, LeafGreen and Emerald only.
+    LiftKey = 356,
+    /// FireRed, LeafGreen and Emerald only.
+    HelixFossil = 357,
+    /// FireRed, LeafGreen and Emerald only.
+    DomeFossil = 358,
+    /// FireRed, LeafGreen and Emerald only.
+    SilphScope = 359,
+    /// FireRed, LeafGreen and Emerald only.
+    Bicycle = 360,
+    /// FireRed, LeafGreen and Emerald only.
+    TownMap = 361,
+    /// FireRed, LeafGreen and Emerald only.
+    VsSeeker = 362,
+    /// FireRed, LeafGreen and Emerald only.
+    FameChecker = 363,
+    /// FireRed, LeafGreen and Emerald only.
+    TmCase = 364,
+    /// FireRed, LeafGreen and Emerald only.
+    BerryPouch = 365,
+    /// FireRed, LeafGreen and Emerald only.
+    TeachyTv = 366,
+    /// FireRed, LeafGreen and Emerald only.
+    TriPass = 367,
+    /// FireRed, LeafGreen and Emerald only.
+    RainbowPass = 368,
+    /// FireRed, LeafGreen and Emerald only.
+    Tea = 369,
+    /// FireRed, LeafGreen and Emerald only.
+    MysticTicket = 370,
+    /// FireRed, LeafGreen and Emerald only.
+    AuroraTicket = 371,
+    /// FireRed, LeafGreen and Emerald only.
+    PowderJar = 372,
+    /// FireRed, LeafGreen and Emerald only.
+    Ruby = 373,
+    /// FireRed, LeafGreen and Emerald only.
+    Sapphire = 374,
+    /// Emerald only.
+    MagmaEmblem = 375,
+    /// Emerald only.
+    OldSeaMap = 376,
+}
+
+impl ItemId {
+    /// Every item, ordered by ID.
+    pub const ALL: [Self; 309] = [
+        Self::MasterBall,
+        Self::UltraBall,
+        Self::GreatBall,
+        Self::PokeBall,
+        Self::SafariBall,
+        Self::NetBall,
+        Self::DiveBall,
+        Self::NestBall,
+        Self::RepeatBall,
+        Self::TimerBall,
+        Self::LuxuryBall,
+        Self::PremierBall,
+        Self::Potion,
+        Self::Antidote,
+        Self::BurnHeal,
+        Self::IceHeal,
+        Self::Awakening,
+        Self::ParalyzeHeal,
+        Self::FullRestore,
+        Self::MaxPotion,
+        Self::HyperPotion,
+        Self::SuperPotion,
+        Self::FullHeal,
+        Self::Revive,
+        Self::MaxRevive,
+        Self::FreshWater,
+        Self::SodaPop,
+        Self::Lemonade,
+        Self::MoomooMilk,
+        Self::EnergyPowder,
+        Self::EnergyRoot,
+        Self::HealPowder,
+        Self::RevivalHerb,
+        Self::Ether,
+        Self::MaxEther,
+        Self::Elixir,
+        Self::MaxElixir,
+        Self::LavaCookie,
+        Self::BlueFlute,
+        Self::YellowFlute,
+        Self::RedFlute,
+        Self::BlackFlute,
+        Self::WhiteFlute,
+        Self::BerryJuice,
+        Self::SacredAsh,
+        Self::ShoalSalt,
+        Self::ShoalShell,
+        Self::RedShard,
+        Self::BlueShard,
+        Self::YellowShard,
+        Self::GreenShard,
+        Self::HpUp,
+        Self::Protein,
+        Self::Iron,
+        Self::Carbos,
+        Self::Calcium,
+        Self::RareCandy,
+        Self::PpUp,
+        Self::Zinc,
+        Self::PpMax,
+        Self::GuardSpec,
+        Self::DireHit,
+        Self::XAttack,
+        Self::XDefend,
+        Self::XSpeed,
+        Self::XAccuracy,
+        Self::XSpecial,
+        Self::PokeDoll,
+        Self::FluffyTail,
+        Self::SuperRepel,
+        Self::MaxRepel,
+        Self::EscapeRope,
+        Self::Repel,
+        Self::SunStone,
+        Self::MoonStone,
+        Self::FireStone,
+        Self::ThunderStone,
+        Self::WaterStone,
+        Self::LeafStone,
+        Self::TinyMushroom,
+        Self::BigMushroom,
+        Self::Pearl,
+        Self::BigPearl,
+        Self::Stardust,
+        Self::StarPiece,
+        Self::Nugget,
+        Self::HeartScale,
+        Self::OrangeMail,
+        Self::HarborMail,
+        Self::GlitterMail,
+        Self::MechMail,
+        Self::WoodMail,
+        Self::WaveMail,
+        Self::BeadMail,
+        Self::ShadowMail,
+        Self::TropicMail,
+        Self::DreamMail,
+        Self::FabMail,
+        Self::RetroMail,
+        Self::CheriBerry,
+        Self::ChestoBerry,
+        Self::PechaBerry,
+        Self::RawstBerry,
+        Self::AspearBerry,
+        Self::LeppaBerry,
+        Self::OranBerry,
+        Self::PersimBerry,
+        Self::LumBerry,
+        Self::SitrusBerry,
+        Self::FigyBerry,
+        Self::WikiBerry,
+        Self::MagoBerry,
+        Self::AguavBerry,
+        Self::IapapaBerry,
+        Self::RazzBerry,
+        Self::BlukBerry,
+        Self::NanabBerry,
+        Self::WepearBerry,
+        Self::PinapBerry,
+        Self::PomegBerry,
+        Self::KelpsyBerry,
+        Self::QualotBerry,
+        Self::HondewBerry,
+        Self::GrepaBerry,
+        Self::TamatoBerry,
+        Self::CornnBerry,
+        Self::MagostBerry,
+        Self::RabutaBerry,
+        Self::NomelBerry,
+        Self::SpelonBerry,
+        Self::PamtreBerry,
+        Self::WatmelBerry,
+        Self::DurinBerry,
+        Self::BelueBerry,
+        Self::LiechiBerry,
+        Self::GanlonBerry,
+        Self::SalacBerry,
+        Self::PetayaBerry,
+        Self::ApicotBerry,
+        Self::LansatBerry,
+        Self::StarfBerry,
+        Self::EnigmaBerry,
+        Self::BrightPowder,
+        Self::WhiteHerb,
+        Self::MachoBrace,
+        Self::ExpShare,
+        Self::QuickClaw,
+        Self::SootheBell,
+        Self::MentalHerb,
+        Self::ChoiceBand,
+        Self::KingsRock,
+        Self::SilverPowder,
+        Self::AmuletCoin,
+        Self::CleanseTag,
+        Self::SoulDew,
+        Self::DeepSeaTooth,
+        Self::DeepSeaScale,
+        Self::SmokeBall,
+        Self::Everstone,
+        Self::FocusBand,
+        Self::LuckyEgg,
+        Self::ScopeLens,
+        Self::MetalCoat,
+        Self::Leftovers,
+        Self::DragonScale,
+        Self::LightBall,
+        Self::SoftSand,
+        Self::HardStone,
+        Self::MiracleSeed,
+        Self::BlackGlasses,
+        Self::BlackBelt,
+        Self::Magnet,
+        Self::MysticWater,
+        Self::SharpBeak,
+        Self::PoisonBarb,
+        Self::NeverMeltIce,
+        Self::SpellTag,
+        Self::TwistedSpoon,
+        Self::Charcoal,
+        Self::DragonFang,
+        Self::SilkScarf,
+        Self::UpGrade,
+        Self::ShellBell,
+        Self::SeaIncense,
+        Self::LaxIncense,
+        Self::LuckyPunch,
+        Self::MetalPowder,
+        Self::ThickClub,
+        Self::Stick,
+        Self::RedScarf,
+        Self::BlueScarf,
+        Self::PinkScarf,
+        Self::GreenScarf,
+        Self::YellowScarf,
+        Self::MachBike,
+        Self::CoinCase,
+        Self::Itemfinder,
+        Self::OldRod,
+        Self::GoodRod,
+        Self::SuperRod,
+        Self::SsTicket,
+        Self::ContestPass,
+        Self::WailmerPail,
+        Self::DevonGoods,
+        Self::SootSack,
+        Self::BasementKey,
+        Self::AcroBike,
+        Self::PokeblockCase,
+        Self::Letter,
+        Self::EonTicket,
+        Self::RedOrb,
+        Self::BlueOrb,
+        Self::Scanner,
+        Self::GoGoggles,
+        Self::Meteorite,
+        Self::Room1Key,
+        Self::Room2Key,
+        Self::Room4Key,
+        Self::Room6Key,
+        Self::StorageKey,
+        Self::RootFossil,
+        Self::ClawFossil,
+        Self::DevonScope,
+        Self::Tm01,
+        Self::Tm02,
+        Self::Tm03,
+        Self::Tm04,
+        Self::Tm05,
+        Self::Tm06,
+        Self::Tm07,
+        Self::Tm08,
+        Self::Tm09,
+        Self::Tm10,
+        Self::Tm11,
+        Self::Tm12,
+        Self::Tm13,
+        Self::Tm14,
+        Self::Tm15,
+        Self::Tm16,
+        Self::Tm17,
+        Self::Tm18,
+        Self::Tm19,
+        Self::Tm20,
+        Self::Tm21,
+        Self::Tm22,
+        Self::Tm23,
+        Self::Tm24,
+        Self::Tm25,
+        Self::Tm26,
+        Self::Tm27,
+        Self::Tm28,
+        Self::Tm29,
+        Self::Tm30,
+        Self::Tm31,
+        Self::Tm32,
+        Self::Tm33,
+        Self::Tm34,
+        Self::Tm35,
+        Self::Tm36,
+        Self::Tm37,
+        Self::Tm38,
+        Self::Tm39,
+        Self::Tm40,
+        Self::Tm41,
+        Self::Tm42,
+        Self::Tm43,
+        Self::Tm44,
+        Self::Tm45,
+        Self::Tm46,
+        Self::Tm47,
+        Self::Tm48,
+        Self::Tm49,
+        Self::Tm50,
+        Self::Hm01,
+        Self::Hm02,
+        Self::Hm03,
+        Self::Hm04,
+        Self::Hm05,
+        Self::Hm06,
+        Self::Hm07,
+        Self::Hm08,
+        Self::OaksParcel,
+        Self::PokeFlute,
+        Self::SecretKey,
+        Self::BikeVoucher,
+        Self::GoldTeeth,
+        Self::OldAmber,
+        Self::CardKey,
+        Self::LiftKey,
+        Self::HelixFossil,
+        Self::DomeFossil,
+        Self::SilphScope,
+        Self::Bicycle,
+        Self::TownMap,
+        Self::VsSeeker,
+        Self::FameChecker,
+        Self::TmCase,
+        Self::BerryPouch,
+        Self::TeachyTv,
+        Self::TriPass,
+        Self::RainbowPass,
+        Self::Tea,
+        Self::MysticTicket,
+        Self::AuroraTicket,
+        Self::PowderJar,
+        Self::Ruby,
+        Self::Sapphire,
+        Self::MagmaEmblem,
+        Self::OldSeaMap,
+    ];
+
+    /// Returns the English name of the item.
+    pub fn name(self) -> &'static str {
+        NAMES[self.index()]
+    }
+
+    pub fn is_mail(self) -> bool {
+        (Self::OrangeMail..=Self::RetroMail).contains(&self)
+    }
+
+    fn index(self) -> usize {
+        Self::ALL
+            .binary_search(&self)
+            .expect("every item is in ItemId::ALL")
+    }
+}
+
+impl From<ItemId> for u16 {
+    fn from(item: ItemId) -> Self {
+        item as u16
+    }
+}
+
+impl TryFrom<u16> for ItemId {
+    type Error = PkError;
+
+    fn try_from(item_id: u16) -> PkResult<Self> {
+        ItemId::ALL
+            .binary_search_by_key(&item_id, |&item| u16::from(item))
+            .map(|index| ItemId::ALL[index])
+            .map_err(|_| PkError::InvalidData("item id"))
+    }
+}
+
+/// Name of every item, in the same order as [`ItemId::ALL`].
+const NAMES: [&str; 309] = [
+    "Master Ball",
+    "Ultra Ball",
+    "Great Ball",
+    "Poké Ball",
+    "Safari Ball",
+    "Net Ball",
+    "Dive Ball",
+    "Nest Ball",
+    "Repeat Ball",
+    "Timer Ball",
+    "Luxury Ball",
+    "Premier Ball",
+    "Potion",
+    "Antidote",
+    "Burn Heal",
+    "Ice Heal",
+    "Awakening",
+    "Paralyze Heal",
+    "Full Restore",
+    "Max Potion",
+    "Hyper Potion",
+    "Super Potion",
+    "Full Heal",
+    "Revive",
+    "Max Revive",
+    "Fresh Water",
+    "Soda Pop",
+    "Lemonade",
+    "Moomoo Milk",
+    "Energy Powder",
+    "Energy Root",
+    "Heal Powder",
+    "Revival Herb",
+    "Ether",
+    "Max Ether",
+    "Elixir",
+    "Max Elixir",
+    "Lava Cookie",
+    "Blue Flute",
+    "Yellow Flute",
+    "Red Flute",
+    "Black Flute",
+    "White Flute",
+    "Berry Juice",
+    "Sacred Ash",
+    "Shoal Salt",
+    "Shoal Shell",
+    "Red Shard",
+    "Blue Shard",
+    "Yellow Shard",
+    "Green Shard",
+    "HP Up",
+    "Protein",
+    "Iron",
+    "Carbos",
+    "Calcium",
+    "Rare Candy",
+    "PP Up",
+    "Zinc",
+    "PP Max",
+    "Guard Spec.",
+    "Dire Hit",
+    "X Attack",
+    "X Defend",
+    "X Speed",
+    "X Accuracy",
+    "X Special",
+    "Poké Doll",
+    "Fluffy Tail",
+    "Super Repel",
+    "Max Repel",
+    "Escape Rope",
+    "Repel",
+    "Sun Stone",
+    "Moon Stone",
+    "Fire Stone",
+    "Thunder Stone",
+    "Water Stone",
+    "Leaf Stone",
+    "Tiny Mushroom",
+    "Big Mushroom",
+    "Pearl",
+    "Big Pearl",
+    "Stardust",
+    "Star Piece",
+    "Nugget",
+    "Heart Scale",
+    "Orange Mail",
+    "Harbor Mail",
+    "Glitter Mail",
+    "Mech Mail",
+    "Wood Mail",
+    "Wave Mail",
+    "Bead Mail",
+    "Shadow Mail",
+    "Tropic Mail",
+    "Dream Mail",
+    "Fab Mail",
+    "Retro Mail",
+    "Cheri Berry",
+    "Chesto Berry",
+    "Pecha Berry",
+    "Rawst Berry",
+    "Aspear Berry",
+    "Leppa Berry",
+    "Oran Berry",
+    "Persim Berry",
+    "Lum Berry",
+    "Sitrus Berry",
+    "Figy Berry",
+    "Wiki Berry",
+    "Mago Berry",
+    "Aguav Berry",
+    "Iapapa Berry",
+    "Razz Berry",
+    "Bluk Berry",
+    "Nanab Berry",
+    "Wepear Berry",
+    "Pinap Berry",
+    "Pomeg Berry",
+    "Kelpsy Berry",
+    "Qualot Berry",
+    "Hondew Berry",
+    "Grepa Berry",
+    "Tamato Berry",
+    "Cornn Berry",
+    "Magost Berry",
+    "Rabuta Berry",
+    "Nomel Berry",
+    "Spelon Berry",
+    "Pamtre Berry",
+    "Watmel Berry",
+    "Durin Berry",
+    "Belue Berry",
+    "Liechi Berry",
+    "Ganlon Berry",
+    "Salac Berry",
+    "Petaya Berry",
+    "Apicot Berry",
+    "Lansat Berry",
+    "Starf Berry",
+    "Enigma Berry",
+    "Bright Powder",
+    "White Herb",
+    "Macho Brace",
+    "Exp. Share",
+    "Quick Claw",
+    "Soothe Bell",
+    "Mental Herb",
+    "Choice Band",
+    "King's Rock",
+    "Silver Powder",
+    "Amulet Coin",
+    "Cleanse Tag",
+    "Soul Dew",
+    "Deep Sea Tooth",
+    "Deep Sea Scale",
+    "Smoke Ball",
+    "Everstone",
+    "Focus Band",
+    "Lucky Egg",
+    "Scope Lens",
+    "Metal Coat",
+    "Leftovers",
+    "Dragon Scale",
+    "Light Ball",
+    "Soft Sand",
+    "Hard Stone",
+    "Miracle Seed",
+    "Black Glasses",
+    "Black Belt",
+    "Magnet",
+    "Mystic Water",
+    "Sharp Beak",
+    "Poison Barb",
+    "Never-Melt Ice",
+    "Spell Tag",
+    "Twisted Spoon",
+    "Charcoal",
+    "Dragon Fang",
+    "Silk Scarf",
+    "Up-Grade",
+    "Shell Bell",
+    "Sea Incense",
+    "Lax Incense",
+    "Lucky Punch",
+    "Metal Powder",
+    "Thick Club",
+    "Stick",
+    "Red Scarf",
+    "Blue Scarf",
+    "Pink Scarf",
+    "Green Scarf",
+    "Yellow Scarf",
+    "Mach Bike",
+    "Coin Case",
+    "Itemfinder",
+    "Old Rod",
+    "Good Rod",
+    "Super Rod",
+    "S.S. Ticket",
+    "Contest Pass",
+    "Wailmer Pail",
+    "Devon Goods",
+    "Soot Sack",
+    "Basement Key",
+    "Acro Bike",
+    "Pokéblock Case",
+    "Letter",
+    "Eon Ticket",
+    "Red Orb",
+    "Blue Orb",
+    "Scanner",
+    "Go-Goggles",
+    "Meteorite",
+    "Rm. 1 Key",
+    "Rm. 2 Key",
+    "Rm. 4 Key",
+    "Rm. 6 Key",
+    "Storage Key",
+    "Root Fossil",
+    "Claw Fossil",
+    "Devon Scope",
+    "TM01",
+    "TM02",
+    "TM03",
+    "TM04",
+    "TM05",
+    "TM06",
+    "TM07",
+    "TM08",
+    "TM09",
+    "TM10",
+    "TM11",
+    "TM12",
+    "TM13",
+    "TM14",
+    "TM15",
+    "TM16",
+    "TM17",
+    "TM18",
+    "TM19",
+    "TM20",
+    "TM21",
+    "TM22",
+    "TM23",
+    "TM24",
+    "TM25",
+    "TM26",
+    "TM27",
+    "TM28",
+    "TM29",
+    "TM30",
+    "TM31",
+    "TM32",
+    "TM33",
+    "TM34",
+    "TM35",
+    "TM36",
+    "TM37",
+    "TM38",
+    "TM39",
+    "TM40",
+    "TM41",
+    "TM42",
+    "TM43",
+    "TM44",
+    "TM45",
+    "TM46",
+    "TM47",
+    "TM48",
+    "TM49",
+    "TM50",
+    "HM01",
+    "HM02",
+    "HM03",
+    "HM04",
+    "HM05",
+    "HM06",
+    "HM07",
+    "HM08",
+    "Oak's Parcel",
+    "Poké Flute",
+    "Secret Key",
+    "Bike Voucher",
+    "Gold Teeth",
+    "Old Amber",
+    "Card Key",
+    "Lift Key",
+    "Helix Fossil",
+    "Dome Fossil",
+    "Silph Scope",
+    "Bicycle",
+    "Town Map",
+    "VS Seeker",
+    "Fame Checker",
+    "TM Case",
+    "Berry Pouch",
+    "Teachy TV",
+    "Tri-Pass",
+    "Rainbow Pass",
+    "Tea",
+    "Mystic Ticket",
+    "Aurora Ticket",
+    "Powder Jar",
+    "Ruby",
+    "Sapphire",
+    "Magma Emblem",
+    "Old Sea Map",
+];
+
+/// Returns the name of the item, if the ID belongs to one.
+pub fn name(item_id: u16) -> Option<&'static str> {
+    ItemId::try_from(item_id).ok().map(ItemId::name)
+}
@@ -0,0 +1,122 @@
+//! Map names, indexed by map bank and map number.
+//!
+//! Only the towns, cities and routes are named, they are all stored in the same map bank: bank 0
+//! in Ruby/Sapphire/Emerald and bank 3 in FireRed/LeafGreen.
+
+use super::GameVersion;
+
+/// Hoenn towns and cities followed by routes 101 to 134, starting at map number 0.
+const HOENN: [&str; 50] = [
+    "Petalburg City",
+    "Slateport City",
+    "Mauville City",
+    "Rustboro City",
+    "Fortree City",
+    "Lilycove City",
+    "Mossdeep City",
+    "Sootopolis City",
+    "Ever Grande City",
+    "Littleroot Town",
+    "Oldale Town",
+    "Dewford Town",
+    "Lavaridge Town",
+    "Fallarbor Town",
+    "Verdanturf Town",
+    "Pacifidlog Town",
+    "Route 101",
+    "Route 102",
+    "Route 103",
+    "Route 104",
+    "Route 105",
+    "Route 106",
+    "Route 107",
+    "Route 108",
+    "Route 109",
+    "Route 110",
+    "Route 111",
+    "Route 112",
+    "Route 113",
+    "Route 114",
+    "Route 115",
+    "Route 116",
+    "Route 117",
+    "Route 118",
+    "Route 119",
+    "Route 120",
+    "Route 121",
+    "Route 122",
+    "Route 123",
+    "Route 124",
+    "Route 125",
+    "Route 126",
+    "Route 127",
+    "Route 128",
+    "Route 129",
+    "Route 130",
+    "Route 131",
+    "Route 132",
+    "Route 133",
+    "Route 134",
+];
+
+/// Kanto and Sevii towns and cities followed by routes 1 to 25 (21 is split in two), starting at
+/// map number 0.
+const KANTO: [&str; 45] = [
+    "Pallet Town",
+    "Viridian City",
+    "Pewter City",
+    "Cerulean City",
+    "Lavender Town",
+    "Vermilion City",
+    "Celadon City",
+    "Fuchsia City",
+    "Cinnabar Island",
+    "Indigo Plateau",
+    "Saffron City",
+    "Saffron City",
+    "One Island",
+    "Two Island",
+    "Three Island",
+    "Four Island",
+    "Five Island",
+    "Seven Island",
+    "Six Island",
+    "Route 1",
+    "Route 2",
+    "Route 3",
+    "Route 4",
+    "Route 5",
+    "Route 6",
+    "Route 7",
+    "Route 8",
+    "Route 9",
+    "Route 10",
+    "Route 11",
+    "Route 12",
+    "Route 13",
+    "Route 14",
+    "Route 15",
+    "Route 16",
+    "Route 17",
+    "Route 18",
+    "Route 19",
+    "Route 20",
+    "Route 21 North",
+    "Route 21 South",
+    "Route 22",
+    "Route 23",
+    "Route 24",
+    "Route 25",
+];
+
+/// Returns the name of the map, if it is a known town, city or route of the version.
+pub fn name(version: GameVersion, bank: u8, number: u8) -> Option<&'static str> {
+    let (names, towns_bank) = match version {
+        GameVersion::RubySapphire | GameVersion::Emerald => (&HOENN[..], 0),
+        GameVersion::FireRedLeafGreen => (&KANTO[..], 3),
+    };
+    if bank != towns_bank {
+        return None;
+    }
+    names.get(number as usize).copied()
+}
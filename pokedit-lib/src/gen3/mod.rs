@@ -1,7 +1,7 @@
 use core::{fmt, ops::Deref};
-use std::{io::Write, ops::DerefMut, path::Path};
+use std::{collections::HashMap, io::Write, ops::DerefMut, path::Path, str::FromStr};
 
-use log::{debug, error};
+use log::{debug, error, warn};
 
 use crate::{
     error::{PkError, PkErrorLoad},
@@ -9,7 +9,26 @@ use crate::{
     PkResult,
 };
 
-pub use crate::common::Gender;
+pub use crate::common::{Gender, Nature, StatKind, Type};
+pub use action_replay::parse_action_replay_code;
+pub use diff::SaveChange;
+pub use pc::{PCBox, PCStorage, PCStorageMut};
+pub use pokemon::{
+    check_pal_park_eligibility, BaseStats, DecryptedPokemon, EvSpread, GrowthRate, IvSet,
+    MigrationWarning, PartyPokemonData, PokemonData, PokemonLocation, StatusCondition,
+};
+pub use species::Species;
+
+pub mod items;
+pub mod maps;
+pub mod moves;
+pub mod pokemon;
+pub mod species;
+pub mod text;
+
+mod action_replay;
+mod diff;
+mod pc;
 
 #[derive(Debug)]
 enum DataSource<'d> {
@@ -73,6 +92,9 @@ pub struct Game<'d> {
     security_key: u32,
 }
 
+/// A [`Game`] owning its save data, e.g. one loaded with [`Game::new_vec`].
+pub type GameOwned = Game<'static>;
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u8)]
 pub enum Validate {
@@ -80,17 +102,54 @@ pub enum Validate {
     #[default]
     Basic = 1,
     Full = 2,
+    /// Repairs both save slots with [`repair_save_slot`] before validating them like
+    /// [`Validate::Full`]. The repairs are written to the save data being loaded. Slots the game
+    /// never saved to are left blank, see [`Data::<SaveSlot>::has_ever_saved`].
+    Repair = 3,
+}
+
+impl Validate {
+    /// Reads the validation level from the environment variable, falling back to the default when
+    /// it is unset or invalid.
+    pub fn from_env(var: &str) -> Validate {
+        std::env::var(var)
+            .ok()
+            .and_then(|level| level.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl FromStr for Validate {
+    type Err = PkError;
+
+    /// Parses `none`, `basic`, `full` or `repair`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(Validate::None),
+            "basic" => Ok(Validate::Basic),
+            "full" => Ok(Validate::Full),
+            "repair" => Ok(Validate::Repair),
+            _ => Err(PkError::InvalidData("validation level")),
+        }
+    }
 }
 
 impl Game<'static> {
+    /// Loads a game that takes ownership of the save data, so it isn't tied to any borrow.
     pub fn new_vec(bytes: Vec<u8>) -> PkResult<Self> {
         Self::raw_new(bytes.into(), Validate::default())
     }
+
+    pub fn new_vec_with_validation(bytes: Vec<u8>, validation: Validate) -> PkResult<Self> {
+        Self::raw_new(bytes.into(), validation)
+    }
 }
 
 impl<'d> Game<'d> {
     /// 128KiB
     const SAVE_FILE_MIN_SIZE: usize = 128 * 1024;
+    /// Sections 1 to 3 are full, section 4 only holds 3848 bytes.
+    const SAVE_BLOCK1_SIZE: usize = 3 * Section::DATA_SIZE + 3848;
 
     pub fn new_bytes(bytes: &'d mut [u8]) -> PkResult<Self> {
         Self::raw_new(bytes.into(), Validate::default())
@@ -100,7 +159,7 @@ impl<'d> Game<'d> {
         Self::raw_new(bytes.into(), validation)
     }
 
-    fn raw_new(bytes: DataSource<'d>, validation: Validate) -> PkResult<Self> {
+    fn raw_new(mut bytes: DataSource<'d>, validation: Validate) -> PkResult<Self> {
         debug!("Loading Gen 3 game with size: {}", bytes.len());
         let emulator_offset = emulator_intro_length(&bytes);
         if emulator_offset > 0 {
@@ -114,12 +173,50 @@ impl<'d> Game<'d> {
             }));
         }
 
+        convert_to_little_endian(&mut bytes[emulator_offset..])?;
+
+        if validation == Validate::Repair {
+            // Like `Game::repair_current_slot` and `Game::repair_backup_slot`.
+            let data = &mut bytes[emulator_offset..];
+            let ((current_offset, current_save_slot), (backup_offset, backup_save_slot)) =
+                SaveSlot::save_slots(data);
+            let save_index = current_save_slot.save_index();
+            let repairs = [
+                (
+                    current_offset,
+                    current_save_slot.has_ever_saved(),
+                    save_index,
+                ),
+                (
+                    backup_offset,
+                    backup_save_slot.has_ever_saved(),
+                    save_index.wrapping_sub(1),
+                ),
+            ];
+            for (offset, ever_saved, save_index) in repairs {
+                if ever_saved {
+                    repair_save_slot(&mut DataMut::from_offset(data, offset), save_index)?;
+                }
+            }
+        }
+
         let (current_save_slot_data, backup_save_slot_data, version, security_key) = {
             let ((current_offset, current_save_slot), (backup_offset, backup_save_slot)) =
                 SaveSlot::save_slots(&bytes[emulator_offset..]);
             current_save_slot.validate(validation)?;
             backup_save_slot.validate(validation)?;
 
+            // Both slots only share a save index when the save was copied over itself, which is
+            // technically valid, so only warn about it.
+            if validation >= Validate::Full
+                && current_save_slot.save_index() == backup_save_slot.save_index()
+            {
+                warn!(
+                    "both save slots share the save index 0x{:08X}, they may not be distinct saves",
+                    current_save_slot.save_index()
+                );
+            }
+
             let trainer_section = current_save_slot.to_sections()?.trainer;
 
             (
@@ -146,46 +243,588 @@ impl<'d> Game<'d> {
         Ok(())
     }
 
-    pub fn save_slot(&self) -> Data<SaveSlot> {
-        Data::from_offset(&self.data, self.current_save_slot_info.offset)
+    pub fn save_slot(&self) -> Data<'_, SaveSlot> {
+        let info = self.current_save_slot_info;
+        Data::from_offset(&self.data, info.offset).with_context(info.view_context())
+    }
+
+    pub fn save_slot_mut(&mut self) -> DataMut<'_, SaveSlot> {
+        let info = self.current_save_slot_info;
+        DataMut::from_offset(&mut self.data, info.offset).with_context(info.view_context())
+    }
+
+    pub fn backup_save_slot(&self) -> Data<'_, SaveSlot> {
+        let info = self.backup_save_slot_info;
+        Data::from_offset(&self.data, info.offset).with_context(info.view_context())
+    }
+
+    fn backup_save_slot_mut(&mut self) -> DataMut<'_, SaveSlot> {
+        let info = self.backup_save_slot_info;
+        DataMut::from_offset(&mut self.data, info.offset).with_context(info.view_context())
+    }
+
+    /// Repairs the current save slot with [`repair_save_slot`], keeping the save index of its
+    /// first section.
+    pub fn repair_current_slot(&mut self) -> PkResult<()> {
+        let save_index = self.save_slot().save_index();
+        repair_save_slot(&mut self.save_slot_mut(), save_index)
+    }
+
+    /// Repairs the backup save slot with [`repair_save_slot`], giving it the save index right
+    /// before the current one so that it stays the older save.
+    pub fn repair_backup_slot(&mut self) -> PkResult<()> {
+        let save_index = self.save_slot().save_index().wrapping_sub(1);
+        repair_save_slot(&mut self.backup_save_slot_mut(), save_index)
+    }
+
+    pub fn current_save_slot_id(&self) -> SaveSlotId {
+        self.save_slot_id(self.current_save_slot_info.offset)
+    }
+
+    pub fn backup_save_slot_id(&self) -> SaveSlotId {
+        self.save_slot_id(self.backup_save_slot_info.offset)
+    }
+
+    fn save_slot_id(&self, offset: usize) -> SaveSlotId {
+        SaveSlotId::from_offset(offset - self.emulator_intro_length)
+            .expect("save slots are always at the offset of slot A or B")
+    }
+
+    /// Returns whether the backup slot holds an older save, which isn't the case until the game
+    /// has been saved twice.
+    pub fn backup_slot_ever_saved(&self) -> bool {
+        self.backup_save_slot().has_ever_saved()
+    }
+
+    /// Validates every section of both save slots, see [`Data::<SaveSlot>::validate_with_report`].
+    pub fn full_validation_report(&self) -> Vec<(SaveSlotKind, Vec<SectionValidationReport>)> {
+        vec![
+            (
+                SaveSlotKind::Current,
+                self.save_slot().validate_with_report(),
+            ),
+            (
+                SaveSlotKind::Backup,
+                self.backup_save_slot().validate_with_report(),
+            ),
+        ]
+    }
+
+    /// Returns the section with the given ID (0 to 13) of the current save slot.
+    pub fn section(&self, id: u16) -> PkResult<Data<'_, Section>> {
+        check_index("section id", usize::from(id), SaveSlot::SECTION_COUNT)?;
+        let offset = self.current_save_slot_info.section_offset(id);
+        Ok(Data::from_offset(&self.data, offset))
+    }
+
+    pub fn section_mut(&mut self, id: u16) -> PkResult<DataMut<'_, Section>> {
+        check_index("section id", usize::from(id), SaveSlot::SECTION_COUNT)?;
+        let offset = self.current_save_slot_info.section_offset(id);
+        Ok(DataMut::from_offset(&mut self.data, offset))
+    }
+
+    pub fn trainer(&self) -> Data<'_, TrainerSection> {
+        Data::from_offset(
+            &self.data,
+            self.current_save_slot_info
+                .section_offset(TrainerSection::ID),
+        )
+    }
+
+    pub fn pokedex(&self) -> Data<'_, PokedexSection> {
+        let offset = self
+            .current_save_slot_info
+            .section_offset(TrainerSection::ID);
+        Data::from_offset(&self.data, offset + PokedexSection::OFFSET)
+    }
+
+    /// Returns the Pokedex flags, see [`Game::set_pokedex_seen`] to mark species as seen.
+    pub fn pokedex_mut(&mut self) -> DataMut<'_, PokedexSection> {
+        let offset = self
+            .current_save_slot_info
+            .section_offset(TrainerSection::ID);
+        DataMut::from_offset(&mut self.data, offset + PokedexSection::OFFSET)
+    }
+
+    /// Marks the species as seen or unseen, both in the Pokedex and in the two copies of the seen
+    /// flags in the save block 1, which the games compare against the Pokedex.
+    pub fn set_pokedex_seen(&mut self, species: Species, value: bool) -> PkResult<()> {
+        let (byte, mask) = PokedexSection::flag_position(species)?;
+        self.pokedex_mut().set_seen(species, value)?;
+        for copy_offset in self.version.pokedex_seen_copy_offsets() {
+            let offset = self.save_block1_offset(copy_offset + byte);
+            if value {
+                self.data[offset] |= mask;
+            } else {
+                self.data[offset] &= !mask;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the Pokemon in the party slot `index` of the current save slot.
+    pub fn party_pokemon(&self, index: usize) -> PkResult<Data<'_, PartyPokemonData>> {
+        self.team_items().party_pokemon(index)
+    }
+
+    /// Writes the party Pokemon to a `.pkm` file, see [`DecryptedPokemon::to_pkm_bytes`]. The
+    /// party stats aren't included.
+    pub fn export_party_pokemon(&self, index: usize, path: impl AsRef<Path>) -> PkResult<()> {
+        let pokemon = self.party_pokemon(index)?.pokemon().decrypt();
+        std::fs::write(path, pokemon.to_pkm_bytes())?;
+        Ok(())
+    }
+
+    /// Overwrites the party Pokemon with a `.pkm` file, see
+    /// [`DecryptedPokemon::from_pkm_bytes`], recalculating its party stats, see
+    /// [`DataMut::<PartyPokemonData>::set_pokemon_with_stats`]. The checksums are updated
+    /// afterwards.
+    pub fn import_party_pokemon(&mut self, index: usize, path: impl AsRef<Path>) -> PkResult<()> {
+        let pokemon = read_pkm(path)?;
+        let mut team_items = self.team_items_mut();
+        check_index("party slot", index, team_items.as_data().team_size())?;
+        if let Some(mut party_pokemon) = team_items.party_mut().nth(index) {
+            party_pokemon.set_pokemon_with_stats(&pokemon)?;
+        }
+        self.update_checksum();
+        Ok(())
     }
 
-    pub fn save_slot_mut(&mut self) -> DataMut<SaveSlot> {
-        DataMut::from_offset(&mut self.data, self.current_save_slot_info.offset)
+    /// Overwrites the Pokemon in the given box slot with a `.pkm` file, see
+    /// [`DecryptedPokemon::from_pkm_bytes`]. The checksums are updated afterwards.
+    pub fn import_box_pokemon(
+        &mut self,
+        box_index: usize,
+        slot: usize,
+        path: impl AsRef<Path>,
+    ) -> PkResult<()> {
+        let pokemon = read_pkm(path)?;
+        self.pc_storage_mut()?
+            .set_pokemon_raw(box_index, slot, &pokemon.to_pkm_bytes())?;
+        self.update_checksum();
+        Ok(())
     }
 
-    pub fn trainer(&self) -> Data<TrainerSection> {
-        Data::from_offset(&self.data, self.current_save_slot_info.trainer)
+    pub fn trainer_mut(&mut self) -> DataMut<'_, TrainerSection> {
+        let offset = self
+            .current_save_slot_info
+            .section_offset(TrainerSection::ID);
+        DataMut::from_offset(&mut self.data, offset)
     }
 
-    pub fn team_items(&self) -> Data<TeamItemsSection> {
-        Data::from_offset(&self.data, self.current_save_slot_info.team_items).with_context(
-            TeamItemsSection {
-                version: self.version,
-                security_key: self.security_key,
-            },
+    pub fn team_items(&self) -> Data<'_, TeamItemsSection> {
+        Data::from_offset(
+            &self.data,
+            self.current_save_slot_info
+                .section_offset(TeamItemsSection::ID),
         )
+        .with_context(TeamItemsSection {
+            version: self.version,
+            security_key: self.security_key,
+        })
     }
 
-    pub fn team_items_mut(&mut self) -> DataMut<TeamItemsSection> {
-        DataMut::from_offset(&mut self.data, self.current_save_slot_info.team_items).with_context(
-            TeamItemsSection {
-                version: self.version,
-                security_key: self.security_key,
-            },
+    pub fn team_items_mut(&mut self) -> DataMut<'_, TeamItemsSection> {
+        DataMut::from_offset(
+            &mut self.data,
+            self.current_save_slot_info
+                .section_offset(TeamItemsSection::ID),
         )
+        .with_context(TeamItemsSection {
+            version: self.version,
+            security_key: self.security_key,
+        })
+    }
+
+    /// Returns the items stored in the player's PC.
+    ///
+    /// Every version has PC item storage, Ruby/Sapphire included, so unlike
+    /// [`Game::pc_storage`] this can't fail. Only its capacity depends on the version, see
+    /// [`GameVersion::pc_items_capacity`].
+    pub fn pc_items(&self) -> Data<'_, PCItemStorage> {
+        Data::from_offset(&self.data, self.pc_items_offset()).with_context(PCItemStorage {
+            version: self.version,
+        })
+    }
+
+    pub fn pc_items_mut(&mut self) -> DataMut<'_, PCItemStorage> {
+        let offset = self.pc_items_offset();
+        DataMut::from_offset(&mut self.data, offset).with_context(PCItemStorage {
+            version: self.version,
+        })
+    }
+
+    /// Returns where every Pokemon originally caught by the given trainer is, in the party or in
+    /// the PC.
+    pub fn find_pokemon_by_trainer_id(&self, ot_id: TrainerId) -> PkResult<Vec<PokemonLocation>> {
+        let mut locations = self
+            .team_items()
+            .party()
+            .enumerate()
+            .filter(|(_, pokemon)| {
+                let pokemon = pokemon.pokemon();
+                pokemon.has_species() && pokemon.ot_id() == ot_id
+            })
+            .map(|(index, _)| PokemonLocation::Party(index))
+            .collect::<Vec<_>>();
+
+        locations.extend(
+            self.pc_storage()?
+                .stored_pokemon()
+                .filter(|(_, _, raw)| Data::<PokemonData>::new(raw).ot_id() == ot_id)
+                .map(|(box_index, slot, _)| PokemonLocation::Box(box_index, slot)),
+        );
+
+        Ok(locations)
+    }
+
+    /// Returns every Pokemon in the party and the PC decrypted, along with its location. Empty
+    /// slots are skipped.
+    pub fn snapshot_all_pokemon(&self) -> PkResult<Vec<(PokemonLocation, DecryptedPokemon)>> {
+        let mut snapshot = self
+            .team_items()
+            .party()
+            .enumerate()
+            .filter(|(_, pokemon)| pokemon.pokemon().has_species())
+            .map(|(index, pokemon)| (PokemonLocation::Party(index), pokemon.pokemon().decrypt()))
+            .collect::<Vec<_>>();
+
+        snapshot.extend(
+            self.pc_storage()?
+                .stored_pokemon()
+                .map(|(box_index, slot, raw)| {
+                    (
+                        PokemonLocation::Box(box_index, slot),
+                        DecryptedPokemon::decrypt(&raw),
+                    )
+                }),
+        );
+
+        Ok(snapshot)
+    }
+
+    /// Returns how many Pokemon are stored across every PC box.
+    pub fn total_stored_pokemon(&self) -> PkResult<usize> {
+        Ok(self.pc_storage()?.stored_pokemon().count())
+    }
+
+    /// Returns whether a Pokemon of the given species is in the party or in the PC. Eggs are not
+    /// considered, as they don't count as owned until they hatch.
+    pub fn has_pokemon(&self, species: Species) -> PkResult<bool> {
+        let species = u16::from(species);
+        Ok(self
+            .snapshot_all_pokemon()?
+            .iter()
+            .any(|(_, pokemon)| !pokemon.is_egg() && pokemon.species() == species))
+    }
+
+    /// Decrypts and re-encrypts every Pokemon in the party and the PC in place, which leaves them
+    /// unchanged. Pokemon with a wrong checksum are skipped, as re-encrypting them would fix it.
+    pub fn re_encrypt_all_pokemon(&mut self) -> PkResult<()> {
+        let snapshot = self.snapshot_all_pokemon()?;
+        let valid = snapshot
+            .into_iter()
+            .filter(|(_, pokemon)| pokemon.is_checksum_valid());
+
+        let mut party = vec![];
+        let mut pc = vec![];
+        for (location, pokemon) in valid {
+            match location {
+                PokemonLocation::Party(index) => party.push((index, pokemon)),
+                PokemonLocation::Box(box_index, slot) => pc.push((box_index, slot, pokemon)),
+            }
+        }
+
+        let mut team_items = self.team_items_mut();
+        for (index, pokemon) in party {
+            if let Some(mut party_pokemon) = team_items.party_mut().nth(index) {
+                party_pokemon.set_pokemon(&pokemon);
+            }
+        }
+
+        let mut pc_storage = self.pc_storage_mut()?;
+        for (box_index, slot, pokemon) in pc {
+            pc_storage.set_pokemon_raw(box_index, slot, &pokemon.encrypt())?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks every Pokemon in the party and the PC for issues that would prevent migrating it
+    /// through the Pal Park, see [`check_pal_park_eligibility`]. Only Pokemon with issues are
+    /// included.
+    pub fn pal_park_eligibility_report(
+        &self,
+    ) -> PkResult<HashMap<PokemonLocation, Vec<MigrationWarning>>> {
+        Ok(self
+            .snapshot_all_pokemon()?
+            .into_iter()
+            .map(|(location, pokemon)| (location, check_pal_park_eligibility(&pokemon)))
+            .filter(|(_, warnings)| !warnings.is_empty())
+            .collect())
+    }
+
+    /// Restores the HP and PP of every Pokemon in the party and cures their status conditions,
+    /// like visiting a Pokemon Center. Eggs and corrupted Pokemon are skipped, and nothing is
+    /// written if any Pokemon knows an invalid move.
+    pub fn heal_all_pokemon(&mut self) -> PkResult<()> {
+        let healed = self
+            .team_items()
+            .party()
+            .map(|party_pokemon| {
+                let mut pokemon = party_pokemon.pokemon().decrypt();
+                // Re-encrypting a Pokemon with a wrong checksum would make the game accept it.
+                if !pokemon.has_species() || pokemon.is_egg() || !pokemon.is_checksum_valid() {
+                    return Ok(None);
+                }
+                pokemon.set_pp(pokemon.max_pp()?);
+                Ok(Some((pokemon, party_pokemon.max_hp())))
+            })
+            .collect::<PkResult<Vec<_>>>()?;
+
+        let mut team_items = self.team_items_mut();
+        for (mut party_pokemon, healed) in team_items.party_mut().zip(healed) {
+            if let Some((pokemon, max_hp)) = healed {
+                party_pokemon.set_pokemon(&pokemon);
+                party_pokemon.set_current_hp(max_hp);
+                party_pokemon.set_status(StatusCondition::default());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets the effort values of the party Pokemon to the given spread. The stats are recalculated
+    /// by the game the next time the Pokemon levels up or is moved through the PC.
+    pub fn max_out_evs(&mut self, party_index: usize, spread: EvSpread) -> PkResult<()> {
+        let party_pokemon =
+            self.team_items()
+                .party()
+                .nth(party_index)
+                .ok_or(PkError::IndexOutOfBounds {
+                    name: "party",
+                    index: party_index,
+                    len: self.team_items().team_size(),
+                })?;
+        let mut pokemon = party_pokemon.pokemon().decrypt();
+        if !pokemon.has_species() || pokemon.is_egg() {
+            return Err(PkError::Msg("only hatched Pokemon can be trained"));
+        }
+        if !pokemon.is_checksum_valid() {
+            return Err(PkError::InvalidData("pokemon checksum"));
+        }
+        pokemon.apply_ev_spread(spread)?;
+
+        let mut team_items = self.team_items_mut();
+        if let Some(mut party_pokemon) = team_items.party_mut().nth(party_index) {
+            party_pokemon.set_pokemon(&pokemon);
+        }
+        Ok(())
+    }
+
+    fn pc_items_offset(&self) -> usize {
+        self.current_save_slot_info
+            .section_offset(TeamItemsSection::ID)
+            + self.version.pc_items_offset()
+    }
+
+    /// Returns how many of the 8 gym badges the player has earned.
+    pub fn badges_earned_count(&self) -> PkResult<u8> {
+        let first_badge = self.version.badge_flags_start();
+        let mut count = 0;
+        for flag in first_badge..(first_badge + 8) {
+            count += self.flag(flag)? as u8;
+        }
+        Ok(count)
+    }
+
+    /// Returns whether the National Pokedex is unlocked, which the games only consider the case
+    /// if a byte in the trainer section, a system flag and a variable all agree.
+    pub fn national_pokedex_unlocked(&self) -> PkResult<bool> {
+        let (magic_offset, magic) = self.version.national_pokedex_magic();
+        let (var, value) = self.version.national_pokedex_var();
+        Ok(self.trainer().data[magic_offset] == magic
+            && self.flag(self.version.national_pokedex_flag())?
+            && self.var(var)? == value)
+    }
+
+    /// Returns how many steps are left before the active repel wears off, 0 if none is active.
+    pub fn repel_steps_remaining(&self) -> PkResult<u8> {
+        Ok(self.var(self.version.repel_steps_var())? as u8)
+    }
+
+    /// Sets the steps left before the repel wears off, a Max Repel lasts 250 steps.
+    pub fn set_repel_steps(&mut self, steps: u8) -> PkResult<()> {
+        self.set_var(self.version.repel_steps_var(), u16::from(steps))
+    }
+
+    /// Returns the Battle Points earned in the Battle Frontier, which only exists in Emerald.
+    pub fn battle_points(&self) -> PkResult<u16> {
+        self.check_battle_frontier()?;
+        Ok(mem::read_half_word(
+            self.trainer().data,
+            TrainerSection::EMERALD_BATTLE_POINTS_OFFSET,
+        ))
+    }
+
+    pub fn set_battle_points(&mut self, battle_points: u16) -> PkResult<()> {
+        self.check_battle_frontier()?;
+        mem::write_half_word(
+            self.trainer_mut().data,
+            TrainerSection::EMERALD_BATTLE_POINTS_OFFSET,
+            battle_points,
+        );
+        Ok(())
+    }
+
+    /// Returns the raw rival name, only FireRed/LeafGreen let the player name the rival.
+    pub fn rival_name_raw(&self) -> PkResult<[u8; TrainerSection::PLAYER_NAME_LENGTH]> {
+        let offset = self.rival_name_offset()?;
+        let mut name = [0; TrainerSection::PLAYER_NAME_LENGTH];
+        for (i, byte) in name.iter_mut().enumerate() {
+            *byte = self.save_block1_byte(offset + i);
+        }
+        Ok(name)
+    }
+
+    /// Decodes the rival name, failing if it has characters without a Unicode equivalent.
+    pub fn rival_name(&self) -> PkResult<String> {
+        text::try_decode(&self.rival_name_raw()?).ok_or(PkError::InvalidData("rival name charset"))
+    }
+
+    /// Encodes and writes the rival name, followed by a terminator, see
+    /// [`DataMut::<TrainerSection>::set_name`].
+    pub fn set_rival_name(&mut self, name: &str) -> PkResult<()> {
+        let offset = self.rival_name_offset()?;
+        let mut bytes = text::encode(name)?;
+        if bytes.len() > TrainerSection::PLAYER_NAME_LENGTH {
+            return Err(PkError::InvalidData("rival name length"));
+        }
+        bytes.resize(TrainerSection::PLAYER_NAME_BUFFER_SIZE, text::TERMINATOR);
+        for (i, byte) in bytes.into_iter().enumerate() {
+            let offset = self.save_block1_offset(offset + i);
+            self.data[offset] = byte;
+        }
+        Ok(())
+    }
+
+    fn rival_name_offset(&self) -> PkResult<usize> {
+        self.version
+            .rival_name_offset()
+            .ok_or(PkError::NotAvailableInGameVersion("rival name"))
+    }
+
+    fn check_battle_frontier(&self) -> PkResult<()> {
+        match self.version {
+            GameVersion::Emerald => Ok(()),
+            _ => Err(PkError::NotAvailableInGameVersion("Battle Points")),
+        }
+    }
+
+    /// Reads a game variable, these are stored in the save block 1.
+    fn var(&self, var: u16) -> PkResult<u16> {
+        let offset = self.var_offset(var);
+        Ok(u16::from_le_bytes([
+            self.save_block1_byte(offset),
+            self.save_block1_byte(offset + 1),
+        ]))
+    }
+
+    fn set_var(&mut self, var: u16, value: u16) -> PkResult<()> {
+        let offset = self.var_offset(var);
+        for (i, byte) in value.to_le_bytes().into_iter().enumerate() {
+            let offset = self.save_block1_offset(offset + i);
+            self.data[offset] = byte;
+        }
+        Ok(())
+    }
+
+    fn var_offset(&self, var: u16) -> usize {
+        debug_assert!(var >= GameVersion::FIRST_VAR, "invalid var 0x{var:04X}");
+        self.version.vars_offset() + usize::from(var - GameVersion::FIRST_VAR) * 2
+    }
+
+    /// Reads an event flag, these are stored in the save block 1.
+    fn flag(&self, flag: u16) -> PkResult<bool> {
+        let byte = self.save_block1_byte(self.version.flags_offset() + flag as usize / 8);
+        Ok(byte & (1 << (flag % 8)) != 0)
+    }
+
+    /// Reads a byte from the save block 1.
+    fn save_block1_byte(&self, offset: usize) -> u8 {
+        self.data[self.save_block1_offset(offset)]
+    }
+
+    /// Returns the offset into the save file of the given offset into the save block 1, which is
+    /// split across sections 1 to 4.
+    fn save_block1_offset(&self, offset: usize) -> usize {
+        debug_assert!(
+            offset < Self::SAVE_BLOCK1_SIZE,
+            "offset {offset} outside save block 1"
+        );
+        let id = TeamItemsSection::ID + (offset / Section::DATA_SIZE) as u16;
+        self.current_save_slot_info.section_offset(id) + offset % Section::DATA_SIZE
+    }
+
+    pub fn wonder_card(&self) -> Data<'_, WonderCardSection> {
+        Data::from_offset(&self.data, self.wonder_card_offset())
+    }
+
+    pub fn wonder_card_mut(&mut self) -> DataMut<'_, WonderCardSection> {
+        let offset = self.wonder_card_offset();
+        DataMut::from_offset(&mut self.data, offset)
+    }
+
+    fn wonder_card_offset(&self) -> usize {
+        self.emulator_intro_length + WonderCardSection::OFFSET
+    }
+
+    pub fn pc_storage(&self) -> PkResult<PCStorage<'_>> {
+        PCStorage::new(self.save_slot())
+    }
+
+    pub fn pc_storage_mut(&mut self) -> PkResult<PCStorageMut<'_>> {
+        PCStorageMut::new(self.save_slot_mut())
+    }
+
+    /// Returns a copy of the given PC box, see [`PCStorage::pc_box`].
+    pub fn pc_box(&self, box_index: usize) -> PkResult<PCBox> {
+        self.pc_storage()?.pc_box(box_index)
     }
 
     pub fn version(&self) -> GameVersion {
         self.version
     }
 
+    /// Checks that the game code still matches the version detected when loading the game, as
+    /// every version-specific offset depends on it.
+    pub fn check_version_consistency(&self) -> PkResult<()> {
+        if GameVersion::from(self.trainer().game_code()) == self.version {
+            Ok(())
+        } else {
+            Err(PkError::InvalidData("version_consistency"))
+        }
+    }
+
     pub fn update_checksum(&mut self) {
         for mut section in self.save_slot_mut().sections_mut() {
             section.update_checksum();
         }
     }
 
+    /// Reorders the sections of the current save slot, see [`DataMut::rearrange_sections`].
+    ///
+    /// Prefer this over calling it through [`Game::save_slot_mut`], as it also updates the cached
+    /// section offsets.
+    pub fn rearrange_sections(
+        &mut self,
+        new_order: [u16; SaveSlot::SECTION_COUNT],
+    ) -> PkResult<()> {
+        self.save_slot_mut().rearrange_sections(new_order)?;
+        self.current_save_slot_info = self.save_slot().to_info(self.current_save_slot_info.offset);
+        Ok(())
+    }
+
     pub fn save(&mut self, save_path: impl AsRef<Path>) -> PkResult<()> {
         self.update_checksum();
         let mut file = std::fs::File::create(save_path.as_ref())?;
@@ -264,7 +903,7 @@ impl<'d, D> DataMut<'d, D>
 where
     D: fmt::Debug + Clone + Copy,
 {
-    pub fn as_data(&self) -> Data<D> {
+    pub fn as_data(&self) -> Data<'_, D> {
         Data {
             data: self.data,
             view_context: self.view_context,
@@ -319,29 +958,52 @@ where
 #[derive(Debug, Clone, Copy)]
 pub struct SaveSlotInfo {
     offset: usize,
-    trainer: usize,
-    team_items: usize,
+    /// The offset into the save file of every section, indexed by section ID.
+    section_offsets: [usize; SaveSlot::SECTION_COUNT],
+}
+
+impl SaveSlotInfo {
+    /// Returns the offset into the save file of every section, indexed by section ID.
+    pub fn all_section_offsets(&self) -> [usize; SaveSlot::SECTION_COUNT] {
+        self.section_offsets
+    }
+
+    fn section_offset(&self, id: u16) -> usize {
+        self.section_offsets[id as usize]
+    }
+
+    /// Returns the view context of the slot, with the cached section offsets made relative to it.
+    fn view_context(&self) -> SaveSlot {
+        SaveSlot {
+            section_offsets: Some(self.section_offsets.map(|offset| offset - self.offset)),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Default)]
-pub struct SaveSlot;
+pub struct SaveSlot {
+    /// The offset into the slot of every section, indexed by section ID, when the view comes
+    /// from a [`Game`], see [`SaveSlotInfo`].
+    section_offsets: Option<[usize; SaveSlot::SECTION_COUNT]>,
+}
 
 impl SaveSlot {
     const SAVE_SLOT_A_OFFSET: usize = 0;
     const SAVE_SLOT_B_OFFSET: usize = Self::SIZE;
-    const SECTION_COUNT: usize = 14;
+    pub const SECTION_COUNT: usize = 14;
 
-    fn save_slots(data: &[u8]) -> ((usize, Data<Self>), (usize, Data<Self>)) {
+    fn save_slots(data: &[u8]) -> ((usize, Data<'_, Self>), (usize, Data<'_, Self>)) {
         let save_slot_a = Data::<Self>::new(data);
         let a_index = save_slot_a.save_index();
         let save_slot_b = Data::<Self>::from_offset(data, Self::SAVE_SLOT_B_OFFSET);
         let b_index = save_slot_b.save_index();
 
+        let a_is_newer = save_slot_index_cmp(a_index, b_index).is_gt();
         debug!(
             "Save indices {{a = 0x{a_index:08X}, b = 0x{b_index:08X}}} - using save index {}",
-            if a_index > b_index { 'a' } else { 'b' }
+            if a_is_newer { 'a' } else { 'b' }
         );
-        if save_slot_a.save_index() > save_slot_b.save_index() {
+        if a_is_newer {
             (
                 (Self::SAVE_SLOT_A_OFFSET, save_slot_a),
                 (Self::SAVE_SLOT_B_OFFSET, save_slot_b),
@@ -364,6 +1026,21 @@ impl<'d> Data<'d, SaveSlot> {
         Data::<'d, Section>::new(self.data).save_index()
     }
 
+    /// Returns how many sections of the slot are blank, see [`Data::<Section>::is_blank`]. Only
+    /// some of them being blank hints at a partially written save.
+    pub fn blank_section_count(&self) -> usize {
+        self.sections().filter(|section| section.is_blank()).count()
+    }
+
+    /// Returns whether the game ever saved to this slot. Blank slots have a save index of 0 (or
+    /// `0xFFFFFFFF` when the flash is erased) and no signature, which isn't the same as a
+    /// corrupted slot.
+    pub fn has_ever_saved(&self) -> bool {
+        let section = Data::<'d, Section>::new(self.data);
+        let blank_index = matches!(section.save_index(), 0 | u32::MAX);
+        !(blank_index && section.signature() != Section::MAGIC_SIGNATURE)
+    }
+
     fn validate(&self, validation: Validate) -> PkResult<()> {
         if validation == Validate::None {
             return Ok(());
@@ -376,10 +1053,10 @@ impl<'d> Data<'d, SaveSlot> {
             sections += 1;
             if section.save_index() != expected_save_index {
                 error!(
-                    "missmatched save index - expected {expected_save_index}, found: {}",
+                    "mismatched save index - expected {expected_save_index}, found: {}",
                     section.save_index(),
                 );
-                return Err(PkError::Load(PkErrorLoad::MissmatchedSaveFileIndex(
+                return Err(PkError::Load(PkErrorLoad::MismatchedSaveFileIndex(
                     expected_save_index,
                     section.save_index(),
                 )));
@@ -411,7 +1088,7 @@ impl<'d> Data<'d, SaveSlot> {
                 TeamItemsSection::ID => {
                     team_items = Some(Data::new(section.data));
                 }
-                2..=13 => {}
+                id if Section::is_valid_id(id) => {}
                 id => {
                     error!("found invalid section id: {id}");
                     return Err(PkError::Load(PkErrorLoad::InvalidSectionId(id)));
@@ -434,19 +1111,14 @@ impl<'d> Data<'d, SaveSlot> {
     pub fn to_info(&self, current_offset: usize) -> SaveSlotInfo {
         let mut info = SaveSlotInfo {
             offset: current_offset,
-            trainer: 0,
-            team_items: 0,
+            section_offsets: [0; SaveSlot::SECTION_COUNT],
         };
 
         for (i, section) in self.sections().enumerate() {
             match section.id() {
-                TrainerSection::ID => {
-                    info.trainer = current_offset + Section::SIZE * i;
-                }
-                TeamItemsSection::ID => {
-                    info.team_items = current_offset + Section::SIZE * i;
+                id if Section::is_valid_id(id) => {
+                    info.section_offsets[id as usize] = current_offset + Section::SIZE * i;
                 }
-                2..=13 => {}
                 id => {
                     panic!("unexpected id {id}, save slot wasn't validated");
                 }
@@ -457,14 +1129,161 @@ impl<'d> Data<'d, SaveSlot> {
     }
 
     pub fn sections(&self) -> impl Iterator<Item = Data<'d, Section>> {
+        self.into_iter()
+    }
+
+    /// Returns which slot of the save file the view points to, by comparing its address with the
+    /// save `data` (without the emulator intro). Returns `None` if the view doesn't point to
+    /// either slot of `data`.
+    pub fn save_slot_id(&self, data: &[u8]) -> Option<SaveSlotId> {
+        let offset = (self.data.as_ptr() as usize).checked_sub(data.as_ptr() as usize)?;
+        SaveSlotId::from_offset(offset).filter(|_| offset + SaveSlot::SIZE <= data.len())
+    }
+
+    /// Validates every section of the save slot, returning the ID of every section that has at
+    /// least one issue, along with its issues.
+    pub fn validate_with_report(&self) -> Vec<SectionValidationReport> {
+        self.sections()
+            .map(|section| (section.id(), section.validate_with_report()))
+            .filter(|(_, issues)| !issues.is_empty())
+            .collect()
+    }
+}
+
+impl<'d> IntoIterator for Data<'d, SaveSlot> {
+    type Item = Data<'d, Section>;
+    type IntoIter = core::iter::Map<core::slice::ChunksExact<'d, u8>, fn(&'d [u8]) -> Self::Item>;
+
+    /// Iterates over the sections of the save slot, like [`Data::<SaveSlot>::sections`].
+    fn into_iter(self) -> Self::IntoIter {
         self.data.chunks_exact(Section::SIZE).map(Data::new)
     }
 }
 
+/// The ID of a section along with every issue found in it.
+pub type SectionValidationReport = (u16, Vec<SectionValidationIssue>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SaveSlotKind {
+    /// The save slot with the latest save.
+    Current,
+    /// The save slot with the previous save.
+    Backup,
+}
+
+/// The physical location of a save slot in the save file, as opposed to [`SaveSlotKind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SaveSlotId {
+    /// The save slot at the start of the save file.
+    A,
+    /// The save slot right after slot A.
+    B,
+}
+
+impl SaveSlotId {
+    fn from_offset(offset: usize) -> Option<Self> {
+        match offset {
+            SaveSlot::SAVE_SLOT_A_OFFSET => Some(SaveSlotId::A),
+            SaveSlot::SAVE_SLOT_B_OFFSET => Some(SaveSlotId::B),
+            _ => None,
+        }
+    }
+}
+
 impl<'d> DataMut<'d, SaveSlot> {
-    pub fn sections_mut(&mut self) -> impl Iterator<Item = DataMut<Section>> {
+    pub fn sections_mut(&mut self) -> impl Iterator<Item = DataMut<'_, Section>> {
         self.data.chunks_exact_mut(Section::SIZE).map(DataMut::new)
     }
+
+    /// Returns the section with the given ID, if the slot has it. Slots from a [`Game`] jump
+    /// straight to it using the section offsets cached when loading the game, other slots are
+    /// searched.
+    pub fn section_by_id_mut(&mut self, id: u16) -> Option<DataMut<'_, Section>> {
+        let offset = match self.view_context.section_offsets {
+            Some(offsets) => *offsets.get(usize::from(id))?,
+            None => {
+                self.as_data()
+                    .sections()
+                    .position(|section| section.id() == id)?
+                    * Section::SIZE
+            }
+        };
+        Some(DataMut::from_offset(self.data, offset))
+    }
+
+    /// Physically reorders the sections of the save slot, so that the section at position `i`
+    /// has the ID `new_order[i]`.
+    ///
+    /// The game only looks at the section IDs, so any order is valid, but many tools expect the
+    /// sections to be in canonical order (i.e. [`Section::VALID_IDS`]).
+    pub fn rearrange_sections(
+        &mut self,
+        new_order: [u16; SaveSlot::SECTION_COUNT],
+    ) -> PkResult<()> {
+        let mut requested = [false; SaveSlot::SECTION_COUNT];
+        for id in new_order {
+            if !Section::is_valid_id(id) || requested[id as usize] {
+                return Err(PkError::Msg(
+                    "the new section order must contain every section ID exactly once",
+                ));
+            }
+            requested[id as usize] = true;
+        }
+
+        let mut positions = [0; SaveSlot::SECTION_COUNT];
+        let mut found = [false; SaveSlot::SECTION_COUNT];
+        for (i, section) in self.as_data().sections().enumerate() {
+            let id = section.id();
+            if !Section::is_valid_id(id) || found[id as usize] {
+                error!("found invalid or duplicated section id: {id}");
+                return Err(PkError::Load(PkErrorLoad::InvalidSectionId(id)));
+            }
+            positions[id as usize] = i;
+            found[id as usize] = true;
+        }
+
+        let original = self.data.to_vec();
+        for (section, id) in self.data.chunks_exact_mut(Section::SIZE).zip(new_order) {
+            let offset = positions[id as usize] * Section::SIZE;
+            section.copy_from_slice(&original[offset..(offset + Section::SIZE)]);
+        }
+
+        if let Some(offsets) = &mut self.view_context.section_offsets {
+            for (i, id) in new_order.into_iter().enumerate() {
+                offsets[id as usize] = i * Section::SIZE;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Fixes the structure of every section of the save slot: writes the magic signature and
+/// `save_index`, then recalculates the checksum. The section IDs and the save data itself are left
+/// untouched, so the slot must not hold invalid section IDs, in which case nothing is written.
+pub fn repair_save_slot(slot: &mut DataMut<SaveSlot>, save_index: u32) -> PkResult<()> {
+    if let Some(id) = slot
+        .as_data()
+        .sections()
+        .map(|section| section.id())
+        .find(|&id| !Section::is_valid_id(id))
+    {
+        return Err(PkError::Load(PkErrorLoad::InvalidSectionId(id)));
+    }
+
+    for mut section in slot.sections_mut() {
+        mem::write_word(
+            section.data,
+            Section::SIGNATURE_OFFSET,
+            Section::MAGIC_SIGNATURE,
+        );
+        mem::write_word(section.data, Section::SAVE_INDEX_OFFSET, save_index);
+        section.update_checksum();
+    }
+
+    Ok(())
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -481,25 +1300,63 @@ impl Section {
     pub const SAVE_INDEX_OFFSET: usize = 0x0FFC;
 
     pub const MAGIC_SIGNATURE: u32 = 0x08012025;
+
+    /// Bytes of save data stored in a section, save blocks split across multiple sections use
+    /// this many bytes of every section but the last one.
+    pub const DATA_SIZE: usize = 3968;
+
+    /// Every section ID that may appear in a save slot, each one exactly once.
+    pub const VALID_IDS: [u16; SaveSlot::SECTION_COUNT] =
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+
+    /// The number of bytes covered by the checksum of every section, by section ID. Only the last
+    /// section of a save block isn't full.
+    pub const VALID_CHECKSUMABLE_SIZES: [(u16, usize); SaveSlot::SECTION_COUNT] = [
+        (0, 3884),
+        (1, Self::DATA_SIZE),
+        (2, Self::DATA_SIZE),
+        (3, Self::DATA_SIZE),
+        (4, 3848),
+        (5, Self::DATA_SIZE),
+        (6, Self::DATA_SIZE),
+        (7, Self::DATA_SIZE),
+        (8, Self::DATA_SIZE),
+        (9, Self::DATA_SIZE),
+        (10, Self::DATA_SIZE),
+        (11, Self::DATA_SIZE),
+        (12, Self::DATA_SIZE),
+        (13, 2000),
+    ];
+
+    pub fn is_valid_id(id: u16) -> bool {
+        Self::VALID_IDS.contains(&id)
+    }
+
+    /// Returns the number of bytes covered by the checksum of the section, see
+    /// [`Section::VALID_CHECKSUMABLE_SIZES`], or `None` if `id` isn't a valid section ID.
+    pub fn checksumable_byte_count(id: u16) -> Option<usize> {
+        Self::VALID_CHECKSUMABLE_SIZES
+            .binary_search_by_key(&id, |&(id, _)| id)
+            .ok()
+            .map(|index| Self::VALID_CHECKSUMABLE_SIZES[index].1)
+    }
 }
 
 impl<'d> Data<'d, Section> {
+    /// Returns the raw bytes of the whole section, including the footer.
+    pub fn as_bytes(&self) -> &'d [u8] {
+        self.data
+    }
+
     pub fn checksum(&self) -> u16 {
         mem::read_half_word(self.data, Section::CHECKSUM_OFFSET)
     }
 
-    pub fn calculate_checksum(&self) -> u16 {
-        let checksumable_bytes = match self.id() {
-            TrainerSection::ID => 3884,
-            TeamItemsSection::ID => 3968,
-            2 => 3968,
-            3 => 3968,
-            4 => 3848,
-            5..=12 => 3968,
-            13 => 2000,
-            id => panic!("invalid id {id}"),
-        };
-        calculate_checksum(&self.data[0..checksumable_bytes])
+    /// Returns the checksum the section should have, or `None` if its ID isn't valid, as the
+    /// number of bytes covered by the checksum depends on it.
+    pub fn calculate_checksum(&self) -> Option<u16> {
+        let checksumable_bytes = Section::checksumable_byte_count(self.id())?;
+        Some(calculate_checksum(&self.data[0..checksumable_bytes]))
     }
 
     pub fn signature(&self) -> u32 {
@@ -514,13 +1371,30 @@ impl<'d> Data<'d, Section> {
         mem::read_half_word(self.data, Section::SECTION_ID_OFFSET)
     }
 
-    pub fn validate(&self, validation: Validate) -> PkResult<()> {
-        if validation == Validate::None {
+    /// Returns whether the section was never written to, i.e. its first word and signature are
+    /// zeroed.
+    pub fn is_blank(&self) -> bool {
+        mem::read_word(self.data, 0) == 0 && self.signature() == 0
+    }
+
+    pub fn section_type(&self) -> SectionType {
+        match self.id() {
+            TrainerSection::ID => SectionType::Trainer,
+            TeamItemsSection::ID => SectionType::TeamItems,
+            id @ 2..=13 => SectionType::PcBuffer((id - 2) as u8),
+            id => SectionType::Unknown(id),
+        }
+    }
+
+    pub fn validate(&self, validation: Validate) -> PkResult<()> {
+        if validation == Validate::None {
             return Ok(());
         }
 
+        let Some(expected_checksum) = self.calculate_checksum() else {
+            return Err(PkError::Load(PkErrorLoad::InvalidSectionId(self.id())));
+        };
         let current_checksum = self.checksum();
-        let expected_checksum = self.calculate_checksum();
 
         if current_checksum != expected_checksum {
             return Err(PkError::Load(PkErrorLoad::InvalidChecksum {
@@ -541,18 +1415,71 @@ impl<'d> Data<'d, Section> {
 
         Ok(())
     }
+
+    /// Validates the section like [`Data::<Section>::validate`], but collects every issue found
+    /// instead of stopping at the first one. The section is valid if no issues are returned.
+    pub fn validate_with_report(&self) -> Vec<SectionValidationIssue> {
+        let mut issues = vec![];
+
+        match self.calculate_checksum() {
+            Some(expected) => {
+                let found = self.checksum();
+                if found != expected {
+                    issues.push(SectionValidationIssue::WrongChecksum { found, expected });
+                }
+            }
+            // The checksum can't be calculated without knowing the contents of the section.
+            None => issues.push(SectionValidationIssue::InvalidSectionId(self.id())),
+        }
+
+        let signature = self.signature();
+        if signature != Section::MAGIC_SIGNATURE {
+            issues.push(SectionValidationIssue::WrongSignature {
+                found: signature,
+                expected: Section::MAGIC_SIGNATURE,
+            });
+        }
+
+        issues
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SectionValidationIssue {
+    WrongChecksum { found: u16, expected: u16 },
+    WrongSignature { found: u32, expected: u32 },
+    InvalidSectionId(u16),
 }
 
 impl<'d> DataMut<'d, Section> {
+    /// Recalculates the checksum of the section. Sections with an invalid ID are left as is, see
+    /// [`Data::<Section>::calculate_checksum`].
     pub fn update_checksum(&mut self) {
-        let checksum = self.as_data().calculate_checksum();
-        mem::write_half_word(self.data, Section::CHECKSUM_OFFSET, checksum);
+        if let Some(checksum) = self.as_data().calculate_checksum() {
+            mem::write_half_word(self.data, Section::CHECKSUM_OFFSET, checksum);
+        }
     }
 }
 
+/// The contents of a section, as determined by its ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SectionType {
+    /// Section 0.
+    Trainer,
+    /// Section 1.
+    TeamItems,
+    /// Sections 2 to 13, the raw PC buffer index goes from 0 to 11.
+    PcBuffer(u8),
+    /// Any other ID, which the games never write.
+    Unknown(u16),
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Sections<'d> {
     trainer: Data<'d, TrainerSection>,
+    #[allow(dead_code)]
     team_items: Data<'d, TeamItemsSection>,
 }
 
@@ -567,6 +1494,8 @@ impl TrainerSection {
     pub const ID: u16 = 0;
     pub const PLAYER_NAME_OFFSET: usize = 0x0000;
     pub const PLAYER_NAME_LENGTH: usize = 7;
+    /// Names are always followed by a terminator, even when they use every character.
+    pub const PLAYER_NAME_BUFFER_SIZE: usize = Self::PLAYER_NAME_LENGTH + 1;
     pub const GAME_CODE_OFFSET: usize = 0x00AC;
     pub const GENDER_OFFSET: usize = 0x0008;
 
@@ -579,6 +1508,19 @@ impl TrainerSection {
     pub const MINUTES_PLAYED_OFFSET: usize = Self::HOURS_PLAYED_OFFSET + 2;
     pub const SECONDS_PLAYED_OFFSET: usize = Self::MINUTES_PLAYED_OFFSET + 1;
     pub const FRAMES_PLAYED_OFFSET: usize = Self::SECONDS_PLAYED_OFFSET + 1;
+
+    pub const OPTIONS_OFFSET: usize = 0x0013;
+    pub const BUTTON_MODE_OFFSET: usize = Self::OPTIONS_OFFSET;
+    pub const TEXT_OPTIONS_OFFSET: usize = Self::BUTTON_MODE_OFFSET + 1;
+    pub const BATTLE_OPTIONS_OFFSET: usize = Self::TEXT_OPTIONS_OFFSET + 1;
+
+    /// Battle Points, stored unencrypted.
+    pub const EMERALD_BATTLE_POINTS_OFFSET: usize = 0x0EB8;
+
+    /// Bit flags for every species in National Pokedex order.
+    pub const POKEDEX_OWNED_OFFSET: usize = 0x0028;
+    pub const POKEDEX_SEEN_OFFSET: usize = 0x005C;
+    pub const POKEDEX_SPECIES_COUNT: usize = 386;
 }
 
 impl<'d> Data<'d, TrainerSection> {
@@ -597,6 +1539,25 @@ impl<'d> Data<'d, TrainerSection> {
             .unwrap()
     }
 
+    /// Decodes the player name, failing if it has characters without a Unicode equivalent.
+    pub fn name(self) -> PkResult<String> {
+        text::try_decode(&self.name_raw()).ok_or(PkError::InvalidData("player name charset"))
+    }
+
+    /// Returns how many species have been registered as owned in the Pokedex.
+    pub fn pokedex_owned_count(self) -> u16 {
+        self.pokedex().caught_count()
+    }
+
+    /// Returns how many species have been registered as seen in the Pokedex.
+    pub fn pokedex_seen_count(self) -> u16 {
+        self.pokedex().seen_count()
+    }
+
+    fn pokedex(self) -> Data<'d, PokedexSection> {
+        Data::from_offset(self.data, PokedexSection::OFFSET)
+    }
+
     pub fn game_code(self) -> u32 {
         mem::read_word(self.data, TrainerSection::GAME_CODE_OFFSET)
     }
@@ -640,15 +1601,152 @@ impl<'d> Data<'d, TrainerSection> {
             n => Ok(n),
         }
     }
+
+    /// Makes the trainer the original trainer of the Pokemon, copying their name, IDs and gender.
+    /// The language of the Pokemon is left as is, as the save doesn't record it.
+    pub fn stamp_as_ot(self, pokemon: &mut DataMut<PokemonData>) -> PkResult<()> {
+        let gender = self.gender()?;
+        // The encryption key depends on the OT ID, so the Pokemon must be decrypted first.
+        let mut decrypted = pokemon.as_data().decrypt();
+        decrypted.set_ot_name_raw(self.name_raw());
+        decrypted.set_ot_id(self.trainer_id());
+        decrypted.set_ot_gender(gender);
+        pokemon.set_pokemon(&decrypted);
+        Ok(())
+    }
+
+    pub fn options(self) -> GameOptions {
+        let text_options = self.data[TrainerSection::TEXT_OPTIONS_OFFSET];
+        let battle_options = self.data[TrainerSection::BATTLE_OPTIONS_OFFSET];
+
+        GameOptions {
+            button_mode: self.data[TrainerSection::BUTTON_MODE_OFFSET],
+            text_speed: text_options & 0b111,
+            frame: text_options >> 3,
+            stereo_sound: battle_options & 0b001 != 0,
+            battle_style_set: battle_options & 0b010 != 0,
+            battle_scene_off: battle_options & 0b100 != 0,
+        }
+    }
+
+    /// Reads every field of the trainer section at once.
+    pub fn full_summary(self) -> PkResult<TrainerSummary> {
+        Ok(TrainerSummary {
+            name: self.name_raw(),
+            gender: self.gender()?,
+            trainer_id: self.trainer_id(),
+            time_played: self.time_played(),
+            game_code: self.game_code(),
+            security_key: self.security_key().ok(),
+            options: self.options(),
+        })
+    }
+}
+
+impl<'d> DataMut<'d, TrainerSection> {
+    /// Encodes and writes the player name, followed by a terminator. Nothing is written if the
+    /// name is longer than 7 characters or has characters that can't be encoded.
+    pub fn set_name(&mut self, name: &str) -> PkResult<()> {
+        let mut bytes = text::encode(name)?;
+        if bytes.len() > TrainerSection::PLAYER_NAME_LENGTH {
+            return Err(PkError::InvalidData("player name length"));
+        }
+        bytes.resize(TrainerSection::PLAYER_NAME_BUFFER_SIZE, text::TERMINATOR);
+        mem::copy_bytes(self.data, TrainerSection::PLAYER_NAME_OFFSET, &bytes);
+        Ok(())
+    }
+
+    /// Sets the player gender, trainers can't be genderless.
+    pub fn set_gender(&mut self, gender: Gender) -> PkResult<()> {
+        if gender == Gender::Genderless {
+            return Err(PkError::InvalidData("gender"));
+        }
+        self.data[TrainerSection::GENDER_OFFSET] = gender as u8;
+        Ok(())
+    }
+
+    /// Sets the time played, nothing is written if it isn't valid, see [`Playtime::is_valid`].
+    pub fn set_time_played(&mut self, time: Playtime) -> PkResult<()> {
+        if !time.is_valid() {
+            return Err(PkError::InvalidData("playtime"));
+        }
+        mem::write_half_word(self.data, TrainerSection::HOURS_PLAYED_OFFSET, time.hours);
+        self.data[TrainerSection::MINUTES_PLAYED_OFFSET] = time.minutes;
+        self.data[TrainerSection::SECONDS_PLAYED_OFFSET] = time.seconds;
+        self.data[TrainerSection::FRAMES_PLAYED_OFFSET] = time.frames;
+        Ok(())
+    }
+
+    /// Sets the trainer ID.
+    ///
+    /// The game decides whether the player owns a Pokemon by comparing its OT ID with the trainer
+    /// ID, so every Pokemon caught so far will be treated as traded (obeying based on badges and
+    /// getting boosted experience) unless it is stamped with the new ID, see
+    /// [`Data::<TrainerSection>::stamp_as_ot`]. The security key is not affected.
+    pub fn set_trainer_id(&mut self, id: TrainerId) {
+        self.set_trainer_id_public(id.public);
+        self.set_trainer_id_private(id.private);
+    }
+
+    /// Sets the public half of the trainer ID, see [`DataMut::<TrainerSection>::set_trainer_id`].
+    pub fn set_trainer_id_public(&mut self, value: u16) {
+        mem::write_half_word(self.data, TrainerSection::PUBLIC_TRAINER_ID_OFFSET, value);
+    }
+
+    /// Sets the private half of the trainer ID, see [`DataMut::<TrainerSection>::set_trainer_id`].
+    pub fn set_trainer_id_private(&mut self, value: u16) {
+        mem::write_half_word(self.data, TrainerSection::PRIVATE_TRAINER_ID_OFFSET, value);
+    }
+}
+
+/// All the information stored in the trainer section. The fields that may not be available in
+/// every version are `None` when missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrainerSummary {
+    pub name: [u8; 7],
+    pub gender: Gender,
+    pub trainer_id: TrainerId,
+    pub time_played: Playtime,
+    pub game_code: u32,
+    pub security_key: Option<u32>,
+    pub options: GameOptions,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameOptions {
+    pub button_mode: u8,
+    pub text_speed: u8,
+    pub frame: u8,
+    pub stereo_sound: bool,
+    pub battle_style_set: bool,
+    pub battle_scene_off: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrainerId {
     pub public: u16,
     pub private: u16,
 }
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+impl TrainerId {
+    /// The trainer ID of a blank save.
+    pub const ZERO: TrainerId = TrainerId {
+        public: 0,
+        private: 0,
+    };
+
+    /// Returns whether both IDs are 0, which is only the case for uninitialized saves.
+    pub const fn is_default(&self) -> bool {
+        self.public == 0 && self.private == 0
+    }
+}
+
+/// Playtimes are ordered by hours, then minutes, seconds and frames.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Playtime {
     pub hours: u16,
     pub minutes: u8,
@@ -656,6 +1754,51 @@ pub struct Playtime {
     pub frames: u8,
 }
 
+impl Playtime {
+    /// Builds a playtime, failing if the minutes, seconds or frames are 60 or more.
+    pub fn new(hours: u16, minutes: u8, seconds: u8, frames: u8) -> PkResult<Self> {
+        let playtime = Self {
+            hours,
+            minutes,
+            seconds,
+            frames,
+        };
+        if playtime.is_valid() {
+            Ok(playtime)
+        } else {
+            Err(PkError::InvalidData("playtime"))
+        }
+    }
+
+    /// Returns whether the minutes, seconds and frames are all between 0 and 59.
+    pub const fn is_valid(&self) -> bool {
+        self.minutes < 60 && self.seconds < 60 && self.frames < 60
+    }
+
+    /// Returns the playtime in seconds, ignoring the frames.
+    pub const fn total_seconds(&self) -> u32 {
+        self.hours as u32 * 3600 + self.minutes as u32 * 60 + self.seconds as u32
+    }
+
+    /// Builds a playtime with 0 frames from a number of seconds. Playtimes that don't fit are
+    /// clamped to `u16::MAX` hours, 59 minutes and 59 seconds.
+    pub const fn from_seconds(seconds: u32) -> Self {
+        const MAX_SECONDS: u32 = u16::MAX as u32 * 3600 + 59 * 60 + 59;
+        let seconds = if seconds > MAX_SECONDS {
+            MAX_SECONDS
+        } else {
+            seconds
+        };
+
+        Self {
+            hours: (seconds / 3600) as u16,
+            minutes: (seconds / 60 % 60) as u8,
+            seconds: (seconds % 60) as u8,
+            frames: 0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct TeamItemsSection {
     version: GameVersion,
@@ -664,6 +1807,24 @@ pub struct TeamItemsSection {
 
 impl TeamItemsSection {
     pub const ID: u16 = 1;
+    /// The save block 1 starts with the player position, followed by the map it is in, which is
+    /// the same in every version.
+    pub const MAP_BANK_OFFSET: usize = 0x0004;
+    pub const MAP_NUMBER_OFFSET: usize = 0x0005;
+    pub const ITEM_SLOT_SIZE: usize = 4;
+    /// The most coins the Coin Case can hold.
+    pub const MAX_COINS: u16 = 9999;
+    /// The most money the player can carry.
+    pub const MAX_MONEY: u32 = 999_999;
+    /// The most of a single item a bag slot can hold.
+    pub const MAX_ITEM_QUANTITY: u16 = 99;
+    pub const PARTY_SIZE: usize = 6;
+
+    /// The coins are stored right after the money, encrypted with the lower half of the security
+    /// key.
+    pub const fn coins_offset(version: GameVersion) -> usize {
+        version.money_offset() + 4
+    }
 
     //fn from_section(section: Section<'d>) -> Self {
     //    debug_assert_eq!(section.id(), Self::ID, "trying to convert invalid section into team/items");
@@ -676,25 +1837,522 @@ impl DataView for TeamItemsSection {
 }
 
 impl<'d> Data<'d, TeamItemsSection> {
+    pub fn map_bank(self) -> u8 {
+        self.data[TeamItemsSection::MAP_BANK_OFFSET]
+    }
+
+    pub fn map_number(self) -> u8 {
+        self.data[TeamItemsSection::MAP_NUMBER_OFFSET]
+    }
+
+    /// Returns the map the player saved in.
+    pub fn current_map(self) -> MapLocation {
+        MapLocation {
+            bank: self.map_bank(),
+            number: self.map_number(),
+        }
+    }
+
     pub fn money(self) -> u32 {
         decrypt_word(
             self.view_context.security_key,
             mem::read_word(self.data, self.view_context.version.money_offset()),
         )
     }
+
+    pub fn coins(self) -> u16 {
+        decrypt_half_word(
+            self.view_context.security_key,
+            mem::read_half_word(
+                self.data,
+                TeamItemsSection::coins_offset(self.view_context.version),
+            ),
+        )
+    }
+
+    /// Returns the non-empty item slots of the given bag pocket.
+    pub fn pocket(self, pocket: PocketKind) -> impl Iterator<Item = Item> + 'd {
+        let version = self.view_context.version;
+        let security_key = self.view_context.security_key;
+        let offset = version.pocket_offset(pocket);
+        let size = version.pocket_capacity(pocket) * TeamItemsSection::ITEM_SLOT_SIZE;
+
+        self.data[offset..(offset + size)]
+            .chunks_exact(TeamItemsSection::ITEM_SLOT_SIZE)
+            .map(move |slot| Item {
+                id: mem::read_half_word(slot, 0),
+                quantity: decrypt_half_word(security_key, mem::read_half_word(slot, 2)),
+            })
+            .filter(|item| item.id != 0)
+    }
+
+    /// Returns the non-empty item slots of every bag pocket.
+    pub fn bag_items(self) -> Bag {
+        Bag {
+            items: self.pocket(PocketKind::Items).collect(),
+            key_items: self.pocket(PocketKind::KeyItems).collect(),
+            poke_balls: self.pocket(PocketKind::PokeBalls).collect(),
+            tms_hms: self.pocket(PocketKind::TmsHms).collect(),
+            berries: self.pocket(PocketKind::Berries).collect(),
+        }
+    }
+
+    /// Returns the number of Pokemon in the party.
+    pub fn team_size(self) -> usize {
+        let team_size =
+            mem::read_word(self.data, self.view_context.version.team_size_offset()) as usize;
+        team_size.min(TeamItemsSection::PARTY_SIZE)
+    }
+
+    /// Returns the Pokemon in the party, in order.
+    pub fn party(self) -> impl Iterator<Item = Data<'d, PartyPokemonData>> + 'd {
+        let offset = self.view_context.version.party_offset();
+        let size = self.team_size() * PartyPokemonData::SIZE;
+        self.data[offset..(offset + size)]
+            .chunks_exact(PartyPokemonData::SIZE)
+            .map(Data::new)
+    }
+
+    /// Returns the Pokemon in the party slot `index`, failing if the slot is empty.
+    pub fn party_pokemon(self, index: usize) -> PkResult<Data<'d, PartyPokemonData>> {
+        check_index("party slot", index, self.team_size())?;
+        let offset = self.view_context.version.party_offset() + index * PartyPokemonData::SIZE;
+        Ok(Data::new(
+            &self.data[offset..(offset + PartyPokemonData::SIZE)],
+        ))
+    }
+
+    /// Returns whether any of the bag pockets contains the given item.
+    pub fn has_item(self, item_id: u16) -> bool {
+        self.find_item(item_id).is_some()
+    }
+
+    /// Returns how many of the given item are in the bag, or 0 if there are none.
+    pub fn item_quantity(self, item_id: u16) -> u16 {
+        self.find_item(item_id).map_or(0, |item| item.quantity)
+    }
+
+    fn find_item(self, item_id: u16) -> Option<Item> {
+        PocketKind::ALL
+            .into_iter()
+            .flat_map(|pocket| self.pocket(pocket))
+            .find(|item| item.id == item_id)
+    }
 }
 
 impl<'d> DataMut<'d, TeamItemsSection> {
-    pub fn set_money(&mut self, value: u32) {
+    /// Sets the Game Corner coins, failing if `value` exceeds [`TeamItemsSection::MAX_COINS`].
+    pub fn set_coins(&mut self, value: u16) -> PkResult<()> {
+        if value > TeamItemsSection::MAX_COINS {
+            return Err(PkError::InvalidData("coins exceeds maximum"));
+        }
+        mem::write_half_word(
+            self.data,
+            TeamItemsSection::coins_offset(self.view_context.version),
+            encrypt_half_word(self.view_context.security_key, value),
+        );
+        Ok(())
+    }
+
+    /// Sets the quantity of the item in the slot `index` of the pocket, a quantity of 0 empties
+    /// the slot. Nothing is written if the slot is empty or the quantity exceeds
+    /// [`TeamItemsSection::MAX_ITEM_QUANTITY`].
+    pub fn set_item_quantity(
+        &mut self,
+        pocket: PocketKind,
+        index: usize,
+        quantity: u16,
+    ) -> PkResult<()> {
+        let version = self.view_context.version;
+        check_index("pocket slot", index, version.pocket_capacity(pocket))?;
+        if quantity > TeamItemsSection::MAX_ITEM_QUANTITY {
+            return Err(PkError::Msg("the bag can hold at most 99 of an item"));
+        }
+        let offset = version.pocket_offset(pocket) + index * TeamItemsSection::ITEM_SLOT_SIZE;
+        if mem::read_half_word(self.data, offset) == 0 {
+            return Err(PkError::Msg("the item slot is empty"));
+        }
+
+        if quantity == 0 {
+            mem::write_half_word(self.data, offset, 0);
+        }
+        mem::write_half_word(
+            self.data,
+            offset + 2,
+            encrypt_half_word(self.view_context.security_key, quantity),
+        );
+        Ok(())
+    }
+
+    pub fn party_mut(&mut self) -> impl Iterator<Item = DataMut<'_, PartyPokemonData>> {
+        let offset = self.view_context.version.party_offset();
+        let size = self.as_data().team_size() * PartyPokemonData::SIZE;
+        self.data[offset..(offset + size)]
+            .chunks_exact_mut(PartyPokemonData::SIZE)
+            .map(DataMut::new)
+    }
+
+    /// Sets the money, failing if `value` exceeds [`TeamItemsSection::MAX_MONEY`].
+    pub fn set_money(&mut self, value: u32) -> PkResult<()> {
+        if value > TeamItemsSection::MAX_MONEY {
+            return Err(PkError::InvalidData("money exceeds maximum"));
+        }
         mem::write_word(
             self.data,
             self.view_context.version.money_offset(),
             encrypt_word(self.view_context.security_key, value),
         );
+        Ok(())
+    }
+}
+
+/// A map, identified by its bank and its number within the bank.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MapLocation {
+    pub bank: u8,
+    pub number: u8,
+}
+
+impl MapLocation {
+    /// Returns the name of the map, see [`maps::name`].
+    pub fn to_name(self, version: GameVersion) -> Option<&'static str> {
+        maps::name(version, self.bank, self.number)
+    }
+}
+
+/// The Pokedex flags in the trainer section, one bit per species in National Pokedex order: the
+/// species number `n` is bit `(n - 1) % 8` of byte `(n - 1) / 8`.
+///
+/// | Offset | Size | Contents |
+/// |--------|------|----------|
+/// | 0x00 | 52 | Caught flags |
+/// | 0x34 | 52 | Seen flags |
+///
+/// The games keep two more copies of the seen flags in the save block 1, and only count a species
+/// as seen if all three agree.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PokedexSection;
+
+impl PokedexSection {
+    /// Offset into the trainer section.
+    pub const OFFSET: usize = TrainerSection::POKEDEX_OWNED_OFFSET;
+    pub const FLAGS_SIZE: usize = 52;
+    pub const SPECIES_COUNT: usize = TrainerSection::POKEDEX_SPECIES_COUNT;
+    pub const CAUGHT_OFFSET: usize = 0;
+    pub const SEEN_OFFSET: usize = TrainerSection::POKEDEX_SEEN_OFFSET - Self::OFFSET;
+
+    /// Returns the byte and bit mask of the species' flag.
+    fn flag_position(species: Species) -> PkResult<(usize, u8)> {
+        let index = species
+            .national_dex()
+            .ok_or(PkError::InvalidData("species"))? as usize
+            - 1;
+        Ok((index / 8, 1 << (index % 8)))
+    }
+}
+
+impl DataView for PokedexSection {
+    const SIZE: usize = 2 * Self::FLAGS_SIZE;
+}
+
+impl<'d> Data<'d, PokedexSection> {
+    /// Returns whether the species is registered as seen, unknown species never are.
+    pub fn seen(self, species: Species) -> bool {
+        self.flag(PokedexSection::SEEN_OFFSET, species)
+    }
+
+    /// Returns whether the species is registered as caught, unknown species never are.
+    pub fn caught(self, species: Species) -> bool {
+        self.flag(PokedexSection::CAUGHT_OFFSET, species)
+    }
+
+    pub fn seen_count(self) -> u16 {
+        self.count(PokedexSection::SEEN_OFFSET)
+    }
+
+    pub fn caught_count(self) -> u16 {
+        self.count(PokedexSection::CAUGHT_OFFSET)
+    }
+
+    /// Returns the percentage (0 to 100) of the species that have been caught.
+    pub fn completion_percentage(self) -> f32 {
+        f32::from(self.caught_count()) / PokedexSection::SPECIES_COUNT as f32 * 100.0
+    }
+
+    /// Counts the flags set for the species, ignoring the unused bits after the last one.
+    fn count(self, offset: usize) -> u16 {
+        let (full_bytes, remaining_bits) = (
+            PokedexSection::SPECIES_COUNT / 8,
+            PokedexSection::SPECIES_COUNT % 8,
+        );
+        let flags = &self.data[offset..(offset + PokedexSection::FLAGS_SIZE)];
+        let count = flags[..full_bytes]
+            .iter()
+            .map(|byte| byte.count_ones())
+            .sum::<u32>()
+            + (flags[full_bytes] & ((1 << remaining_bits) - 1)).count_ones();
+        count as u16
+    }
+
+    fn flag(self, offset: usize, species: Species) -> bool {
+        PokedexSection::flag_position(species)
+            .is_ok_and(|(byte, mask)| self.data[offset + byte] & mask != 0)
+    }
+}
+
+impl<'d> DataMut<'d, PokedexSection> {
+    /// Only updates the seen flags of the Pokedex, the games ignore them unless the copies in the
+    /// save block 1 match, which [`Game::set_pokedex_seen`] takes care of.
+    pub fn set_seen(&mut self, species: Species, value: bool) -> PkResult<()> {
+        self.set_flag(PokedexSection::SEEN_OFFSET, species, value)
+    }
+
+    pub fn set_caught(&mut self, species: Species, value: bool) -> PkResult<()> {
+        self.set_flag(PokedexSection::CAUGHT_OFFSET, species, value)
+    }
+
+    fn set_flag(&mut self, offset: usize, species: Species, value: bool) -> PkResult<()> {
+        let (byte, mask) = PokedexSection::flag_position(species)?;
+        if value {
+            self.data[offset + byte] |= mask;
+        } else {
+            self.data[offset + byte] &= !mask;
+        }
+        Ok(())
+    }
+}
+
+/// The items stored in the player's PC, which live in the team/items section right after the
+/// money. Unlike the bag, quantities are not encrypted.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PCItemStorage {
+    version: GameVersion,
+}
+
+impl PCItemStorage {
+    /// The most of a single item the PC can hold.
+    pub const MAX_QUANTITY: u16 = 999;
+}
+
+impl DataView for PCItemStorage {
+    /// Emerald has the biggest storage, with 50 slots.
+    const SIZE: usize = 50 * TeamItemsSection::ITEM_SLOT_SIZE;
+}
+
+impl<'d> Data<'d, PCItemStorage> {
+    /// Returns the number of item slots in the PC.
+    pub fn capacity(self) -> usize {
+        self.view_context.version.pc_items_capacity()
+    }
+
+    /// Returns the non-empty item slots.
+    pub fn items(self) -> impl Iterator<Item = Item> + 'd {
+        let size = self.capacity() * TeamItemsSection::ITEM_SLOT_SIZE;
+        self.data[..size]
+            .chunks_exact(TeamItemsSection::ITEM_SLOT_SIZE)
+            .map(|slot| Item {
+                id: mem::read_half_word(slot, 0),
+                quantity: mem::read_half_word(slot, 2),
+            })
+            .filter(|item| item.id != 0)
+    }
+
+    pub fn item_quantity(self, item_id: u16) -> u16 {
+        self.items()
+            .find(|item| item.id == item_id)
+            .map_or(0, |item| item.quantity)
     }
 }
 
+impl<'d> DataMut<'d, PCItemStorage> {
+    /// Adds `quantity` of the item to the PC, either to the slot already holding it or to the
+    /// first empty slot. Nothing is written if the PC is full or the quantity would exceed
+    /// [`PCItemStorage::MAX_QUANTITY`].
+    pub fn add_item(&mut self, item_id: u16, quantity: u16) -> PkResult<()> {
+        if item_id == 0 {
+            return Err(PkError::Msg("cannot add an empty item to the PC"));
+        }
+
+        let slot = match self.find_slot(item_id) {
+            Some(slot) => slot,
+            None => self
+                .find_slot(0)
+                .ok_or(PkError::Msg("the PC item storage is full"))?,
+        };
+        let offset = slot * TeamItemsSection::ITEM_SLOT_SIZE;
+        let current = mem::read_half_word(self.data, offset + 2);
+        let total = current
+            .checked_add(quantity)
+            .filter(|&total| total <= PCItemStorage::MAX_QUANTITY)
+            .ok_or(PkError::Msg("the PC can hold at most 999 of an item"))?;
+
+        mem::write_half_word(self.data, offset, item_id);
+        mem::write_half_word(self.data, offset + 2, total);
+        Ok(())
+    }
+
+    /// Overwrites the slot `index` with `quantity` of the item. Nothing is written if the item
+    /// doesn't exist in this version or the quantity exceeds [`PCItemStorage::MAX_QUANTITY`].
+    pub fn set_item(&mut self, index: usize, item: items::ItemId, quantity: u16) -> PkResult<()> {
+        check_index("PC item slot", index, self.as_data().capacity())?;
+        if !self.view_context.version.has_item(item) {
+            return Err(PkError::NotAvailableInGameVersion("item"));
+        }
+        if quantity > PCItemStorage::MAX_QUANTITY {
+            return Err(PkError::Msg("the PC can hold at most 999 of an item"));
+        }
+
+        let offset = index * TeamItemsSection::ITEM_SLOT_SIZE;
+        mem::write_half_word(self.data, offset, u16::from(item));
+        mem::write_half_word(self.data, offset + 2, quantity);
+        Ok(())
+    }
+
+    /// Removes `quantity` of the item from the PC. Emptied slots are removed, and the items after
+    /// them moved up, as the game does.
+    pub fn remove_item(&mut self, item_id: u16, quantity: u16) -> PkResult<()> {
+        let slot = self
+            .find_slot(item_id)
+            .filter(|_| item_id != 0)
+            .ok_or(PkError::Msg("the item is not in the PC"))?;
+        let offset = slot * TeamItemsSection::ITEM_SLOT_SIZE;
+        let remaining = mem::read_half_word(self.data, offset + 2)
+            .checked_sub(quantity)
+            .ok_or(PkError::Msg("the PC does not hold enough of the item"))?;
+
+        if remaining > 0 {
+            mem::write_half_word(self.data, offset + 2, remaining);
+            return Ok(());
+        }
+
+        let end = self.as_data().capacity() * TeamItemsSection::ITEM_SLOT_SIZE;
+        self.data
+            .copy_within((offset + TeamItemsSection::ITEM_SLOT_SIZE)..end, offset);
+        self.data[(end - TeamItemsSection::ITEM_SLOT_SIZE)..end].fill(0);
+        Ok(())
+    }
+
+    fn find_slot(&self, item_id: u16) -> Option<usize> {
+        (0..self.as_data().capacity()).find(|slot| {
+            mem::read_half_word(self.data, slot * TeamItemsSection::ITEM_SLOT_SIZE) == item_id
+        })
+    }
+}
+
+/// The Mystery Gift/e-Reader block at the end of the save file, holding the event last received.
+///
+/// | Offset | Size | Contents |
+/// |--------|------|----------|
+/// | 0x0000 | 2 | Event ID |
+/// | 0x0002 | 20 | Event name |
+/// | 0x0016 | 4074 | Event data |
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WonderCardSection;
+
+impl WonderCardSection {
+    pub const OFFSET: usize = 0x1E000;
+    pub const EVENT_ID_OFFSET: usize = 0x0000;
+    pub const EVENT_NAME_OFFSET: usize = 0x0002;
+    pub const EVENT_NAME_LENGTH: usize = 20;
+    pub const EVENT_DATA_OFFSET: usize = Self::EVENT_NAME_OFFSET + Self::EVENT_NAME_LENGTH;
+    pub const MAX_EVENT_DATA_SIZE: usize = Self::SIZE - Self::EVENT_DATA_OFFSET;
+}
+
+impl DataView for WonderCardSection {
+    const SIZE: usize = 4096;
+}
+
+impl<'d> Data<'d, WonderCardSection> {
+    /// Returns whether no event was ever received, i.e. the block is zeroed or erased.
+    pub fn is_empty(self) -> bool {
+        self.data.iter().all(|&b| b == 0) || self.data.iter().all(|&b| b == 0xFF)
+    }
+
+    pub fn event_id(self) -> u16 {
+        mem::read_half_word(self.data, WonderCardSection::EVENT_ID_OFFSET)
+    }
+
+    pub fn event_name_raw(self) -> [u8; WonderCardSection::EVENT_NAME_LENGTH] {
+        self.data[WonderCardSection::EVENT_NAME_OFFSET
+            ..(WonderCardSection::EVENT_NAME_OFFSET + WonderCardSection::EVENT_NAME_LENGTH)]
+            .try_into()
+            .unwrap()
+    }
+}
+
+impl<'d> DataMut<'d, WonderCardSection> {
+    /// Replaces the stored event with the event `id` and its `data`, the event name and the rest
+    /// of the block are zeroed. Nothing is written if `data` doesn't fit.
+    pub fn inject_event(&mut self, id: u16, data: &[u8]) -> PkResult<()> {
+        if data.len() > WonderCardSection::MAX_EVENT_DATA_SIZE {
+            return Err(PkError::InvalidData("event data size"));
+        }
+
+        self.data.fill(0);
+        mem::write_half_word(self.data, WonderCardSection::EVENT_ID_OFFSET, id);
+        mem::copy_bytes(self.data, WonderCardSection::EVENT_DATA_OFFSET, data);
+        Ok(())
+    }
+
+    pub fn set_event_name_raw(&mut self, name: [u8; WonderCardSection::EVENT_NAME_LENGTH]) {
+        mem::copy_bytes(self.data, WonderCardSection::EVENT_NAME_OFFSET, &name);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Item {
+    pub id: u16,
+    pub quantity: u16,
+}
+
+/// The contents of every bag pocket, see [`Data::<TeamItemsSection>::bag_items`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bag {
+    pub items: Vec<Item>,
+    pub key_items: Vec<Item>,
+    pub poke_balls: Vec<Item>,
+    pub tms_hms: Vec<Item>,
+    pub berries: Vec<Item>,
+}
+
+impl Bag {
+    pub fn pocket(&self, pocket: PocketKind) -> &[Item] {
+        match pocket {
+            PocketKind::Items => &self.items,
+            PocketKind::KeyItems => &self.key_items,
+            PocketKind::PokeBalls => &self.poke_balls,
+            PocketKind::TmsHms => &self.tms_hms,
+            PocketKind::Berries => &self.berries,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PocketKind {
+    Items,
+    KeyItems,
+    PokeBalls,
+    TmsHms,
+    Berries,
+}
+
+impl PocketKind {
+    /// All the bag pockets, in the order they are displayed in game.
+    pub const ALL: [PocketKind; 5] = [
+        PocketKind::Items,
+        PocketKind::KeyItems,
+        PocketKind::PokeBalls,
+        PocketKind::TmsHms,
+        PocketKind::Berries,
+    ];
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum GameVersion {
     #[default]
@@ -704,6 +2362,36 @@ pub enum GameVersion {
 }
 
 impl GameVersion {
+    /// The ID of the first game variable, IDs below it are reserved for special variables.
+    pub const FIRST_VAR: u16 = 0x4000;
+
+    /// Returns every game version, in release order.
+    pub const fn all() -> [GameVersion; 3] {
+        [
+            GameVersion::RubySapphire,
+            GameVersion::FireRedLeafGreen,
+            GameVersion::Emerald,
+        ]
+    }
+
+    pub const fn count() -> usize {
+        Self::all().len()
+    }
+
+    /// Returns the highest item ID the version knows.
+    pub const fn last_item_id(self) -> u16 {
+        match self {
+            GameVersion::RubySapphire => items::ItemId::Hm08 as u16,
+            GameVersion::FireRedLeafGreen => items::ItemId::Sapphire as u16,
+            GameVersion::Emerald => items::ItemId::OldSeaMap as u16,
+        }
+    }
+
+    /// Returns whether the item exists in this version.
+    pub const fn has_item(self, item: items::ItemId) -> bool {
+        item as u16 <= self.last_item_id()
+    }
+
     /// Returns the offset into the trainer section where the security key is stored.
     pub const fn security_key_offset(self) -> usize {
         match self {
@@ -713,6 +2401,17 @@ impl GameVersion {
         }
     }
 
+    /// Returns the security key to use for blank saves. Ruby/Sapphire don't encrypt anything.
+    /// Emerald stores its key where the other versions store their game code, so it must be
+    /// neither 0 nor 1 for the save to be detected as Emerald.
+    pub const fn default_security_key(self) -> u32 {
+        match self {
+            GameVersion::RubySapphire => 0,
+            GameVersion::FireRedLeafGreen => 1,
+            GameVersion::Emerald => 2,
+        }
+    }
+
     /// Returns the offset into the team/items section where the player's money is stored.
     pub const fn money_offset(self) -> usize {
         match self {
@@ -720,6 +2419,169 @@ impl GameVersion {
             GameVersion::FireRedLeafGreen => 0x0290,
         }
     }
+
+    /// Returns the offset into the team/items section where the number of party Pokemon is stored.
+    pub const fn team_size_offset(self) -> usize {
+        match self {
+            GameVersion::RubySapphire | GameVersion::Emerald => 0x0234,
+            GameVersion::FireRedLeafGreen => 0x0034,
+        }
+    }
+
+    /// Returns the offset into the team/items section where the party Pokemon start.
+    pub const fn party_offset(self) -> usize {
+        self.team_size_offset() + 4
+    }
+
+    /// Returns the offset into the team/items section where the PC items are stored.
+    pub const fn pc_items_offset(self) -> usize {
+        match self {
+            GameVersion::RubySapphire | GameVersion::Emerald => 0x0498,
+            GameVersion::FireRedLeafGreen => 0x0298,
+        }
+    }
+
+    /// Returns the number of item slots in the PC.
+    pub const fn pc_items_capacity(self) -> usize {
+        match self {
+            GameVersion::RubySapphire | GameVersion::Emerald => 50,
+            GameVersion::FireRedLeafGreen => 30,
+        }
+    }
+
+    /// Returns the offset into the save block 1 of the rival name. Ruby/Sapphire and Emerald
+    /// don't store it, as the rival is always Brendan or May.
+    pub const fn rival_name_offset(self) -> Option<usize> {
+        match self {
+            GameVersion::FireRedLeafGreen => Some(0x3A4C),
+            GameVersion::RubySapphire | GameVersion::Emerald => None,
+        }
+    }
+
+    /// Returns the offsets into the save block 1 of the two copies of the Pokedex seen flags.
+    pub const fn pokedex_seen_copy_offsets(self) -> [usize; 2] {
+        match self {
+            GameVersion::RubySapphire => [0x0938, 0x3A8C],
+            GameVersion::FireRedLeafGreen => [0x05F8, 0x3A18],
+            GameVersion::Emerald => [0x0988, 0x3B24],
+        }
+    }
+
+    /// Returns the offset into the save block 1 (sections 1 to 4) where the event flags start.
+    pub const fn flags_offset(self) -> usize {
+        match self {
+            GameVersion::RubySapphire => 0x1220,
+            GameVersion::FireRedLeafGreen => 0x0EE0,
+            GameVersion::Emerald => 0x1270,
+        }
+    }
+
+    /// Returns the offset into the save block 1 where the game variables start, right after the
+    /// event flags. Emerald has 0x12C bytes of flags instead of 0x120.
+    pub const fn vars_offset(self) -> usize {
+        match self {
+            GameVersion::RubySapphire => 0x1340,
+            GameVersion::FireRedLeafGreen => 0x1000,
+            GameVersion::Emerald => 0x139C,
+        }
+    }
+
+    /// Returns the game variable holding the steps left before the repel wears off.
+    pub const fn repel_steps_var(self) -> u16 {
+        match self {
+            GameVersion::RubySapphire | GameVersion::Emerald => 0x4021,
+            GameVersion::FireRedLeafGreen => 0x4020,
+        }
+    }
+
+    /// Returns the offset into the trainer section and the value of the byte which must be set
+    /// for the National Pokedex to be unlocked.
+    pub const fn national_pokedex_magic(self) -> (usize, u8) {
+        match self {
+            GameVersion::RubySapphire | GameVersion::Emerald => (0x001A, 0xDA),
+            GameVersion::FireRedLeafGreen => (0x001B, 0xB9),
+        }
+    }
+
+    /// Returns the system flag set when the National Pokedex is unlocked.
+    pub const fn national_pokedex_flag(self) -> u16 {
+        match self {
+            GameVersion::RubySapphire => 0x0836,
+            GameVersion::FireRedLeafGreen => 0x0840,
+            GameVersion::Emerald => 0x0896,
+        }
+    }
+
+    /// Returns the variable and the value it holds when the National Pokedex is unlocked.
+    pub const fn national_pokedex_var(self) -> (u16, u16) {
+        match self {
+            GameVersion::RubySapphire | GameVersion::Emerald => (0x4046, 0x0302),
+            GameVersion::FireRedLeafGreen => (0x404E, 0x6258),
+        }
+    }
+
+    /// Returns the event flag of the first gym badge, the other 7 badges follow it.
+    pub const fn badge_flags_start(self) -> u16 {
+        match self {
+            GameVersion::RubySapphire => 0x0807,
+            GameVersion::FireRedLeafGreen => 0x0820,
+            GameVersion::Emerald => 0x0867,
+        }
+    }
+
+    /// Returns the offset into the team/items section where the given bag pocket starts.
+    pub const fn pocket_offset(self, pocket: PocketKind) -> usize {
+        match (self, pocket) {
+            (GameVersion::RubySapphire, PocketKind::Items) => 0x0560,
+            (GameVersion::RubySapphire, PocketKind::KeyItems) => 0x05B0,
+            (GameVersion::RubySapphire, PocketKind::PokeBalls) => 0x0600,
+            (GameVersion::RubySapphire, PocketKind::TmsHms) => 0x0640,
+            (GameVersion::RubySapphire, PocketKind::Berries) => 0x0740,
+            (GameVersion::FireRedLeafGreen, PocketKind::Items) => 0x0310,
+            (GameVersion::FireRedLeafGreen, PocketKind::KeyItems) => 0x03B8,
+            (GameVersion::FireRedLeafGreen, PocketKind::PokeBalls) => 0x0430,
+            (GameVersion::FireRedLeafGreen, PocketKind::TmsHms) => 0x0464,
+            (GameVersion::FireRedLeafGreen, PocketKind::Berries) => 0x054C,
+            (GameVersion::Emerald, PocketKind::Items) => 0x0560,
+            (GameVersion::Emerald, PocketKind::KeyItems) => 0x05D8,
+            (GameVersion::Emerald, PocketKind::PokeBalls) => 0x0650,
+            (GameVersion::Emerald, PocketKind::TmsHms) => 0x0690,
+            (GameVersion::Emerald, PocketKind::Berries) => 0x0790,
+        }
+    }
+
+    /// Returns the number of item slots in the given bag pocket.
+    pub const fn pocket_capacity(self, pocket: PocketKind) -> usize {
+        match (self, pocket) {
+            (GameVersion::RubySapphire, PocketKind::Items) => 20,
+            (GameVersion::RubySapphire, PocketKind::KeyItems) => 20,
+            (GameVersion::RubySapphire, PocketKind::PokeBalls) => 16,
+            (GameVersion::RubySapphire, PocketKind::TmsHms) => 64,
+            (GameVersion::RubySapphire, PocketKind::Berries) => 46,
+            (GameVersion::FireRedLeafGreen, PocketKind::Items) => 42,
+            (GameVersion::FireRedLeafGreen, PocketKind::KeyItems) => 30,
+            (GameVersion::FireRedLeafGreen, PocketKind::PokeBalls) => 13,
+            (GameVersion::FireRedLeafGreen, PocketKind::TmsHms) => 58,
+            (GameVersion::FireRedLeafGreen, PocketKind::Berries) => 43,
+            (GameVersion::Emerald, PocketKind::Items) => 30,
+            (GameVersion::Emerald, PocketKind::KeyItems) => 30,
+            (GameVersion::Emerald, PocketKind::PokeBalls) => 16,
+            (GameVersion::Emerald, PocketKind::TmsHms) => 64,
+            (GameVersion::Emerald, PocketKind::Berries) => 46,
+        }
+    }
+}
+
+impl fmt::Display for PocketKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PocketKind::Items => write!(f, "Items"),
+            PocketKind::KeyItems => write!(f, "Key Items"),
+            PocketKind::PokeBalls => write!(f, "Poke Balls"),
+            PocketKind::TmsHms => write!(f, "TMs/HMs"),
+            PocketKind::Berries => write!(f, "Berries"),
+        }
+    }
 }
 
 impl fmt::Display for GameVersion {
@@ -744,6 +2606,7 @@ impl From<u32> for GameVersion {
 }
 
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Language {
     #[default]
@@ -755,6 +2618,105 @@ pub enum Language {
     Spanish = 7,
 }
 
+impl Language {
+    /// Pokemon created before the language was tracked have no language.
+    pub const NO_LANGUAGE: u8 = 0xFF;
+
+    /// Parses the language byte of a Pokemon, which is `None` for [`Language::NO_LANGUAGE`].
+    pub fn from_pokemon_language(byte: u8) -> PkResult<Option<Language>> {
+        match byte {
+            Self::NO_LANGUAGE => Ok(None),
+            byte => Language::try_from(byte).map(Some),
+        }
+    }
+}
+
+impl TryFrom<u8> for Language {
+    type Error = PkError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Language::Japanese),
+            2 => Ok(Language::English),
+            3 => Ok(Language::French),
+            4 => Ok(Language::Italian),
+            5 => Ok(Language::German),
+            7 => Ok(Language::Spanish),
+            _ => Err(PkError::InvalidData("language")),
+        }
+    }
+}
+
+fn check_index(name: &'static str, index: usize, len: usize) -> PkResult<()> {
+    if index < len {
+        Ok(())
+    } else {
+        Err(PkError::IndexOutOfBounds { name, index, len })
+    }
+}
+
+/// Reads a Pokemon from a `.pkm` file, which must be exactly [`PokemonData::SIZE`] bytes long.
+fn read_pkm(path: impl AsRef<Path>) -> PkResult<DecryptedPokemon> {
+    let bytes = std::fs::read(path)?;
+    let pkm = bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| PkError::InvalidData("pkm size"))?;
+    DecryptedPokemon::from_pkm_bytes(pkm)
+}
+
+/// Reads the save file at `path` and checks it passes [`Validate::Full`], returning the first
+/// issue found.
+pub fn verify_save_integrity(path: impl AsRef<Path>) -> PkResult<()> {
+    let mut bytes = std::fs::read(path)?;
+    Game::new_with_validation(&mut bytes, Validate::Full)?;
+    Ok(())
+}
+
+/// Compares two save indices, taking into account that they wrap around, so that the newer save
+/// compares as greater. `a` is newer if it is less than `0x80000000` saves ahead of `b`.
+pub fn save_slot_index_cmp(a: u32, b: u32) -> std::cmp::Ordering {
+    match a.wrapping_sub(b) {
+        0 => std::cmp::Ordering::Equal,
+        1..0x8000_0000 => std::cmp::Ordering::Greater,
+        _ => std::cmp::Ordering::Less,
+    }
+}
+
+/// Converts a save whose 32-bit words were byte-swapped (i.e. stored as big endian) back to little
+/// endian, in place.
+///
+/// The save is considered byte-swapped when no section of either save slot has the magic
+/// signature, but at least one does once the bytes are swapped. Any other save is left as is.
+// `usize::is_multiple_of` was only stabilized in Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
+pub fn convert_to_little_endian(data: &mut [u8]) -> PkResult<()> {
+    let signatures = |data: &[u8], signature: [u8; 4]| {
+        data.chunks_exact(Section::SIZE)
+            .take(2 * SaveSlot::SECTION_COUNT)
+            .any(|section| {
+                section[Section::SIGNATURE_OFFSET..(Section::SIGNATURE_OFFSET + 4)] == signature
+            })
+    };
+
+    if signatures(data, Section::MAGIC_SIGNATURE.to_le_bytes())
+        || !signatures(data, Section::MAGIC_SIGNATURE.to_be_bytes())
+    {
+        return Ok(());
+    }
+
+    if data.len() % 4 != 0 {
+        return Err(PkError::InvalidData("byte-swapped save size"));
+    }
+
+    debug!("Converting byte-swapped save to little endian");
+    for word in data.chunks_exact_mut(4) {
+        word.reverse();
+    }
+
+    Ok(())
+}
+
 /// Returns the length of the emulator intro of the save file.
 const fn emulator_intro_length(_: &[u8]) -> usize {
     const GNUBOY_OFFSET: usize = 0;
@@ -783,3 +2745,17 @@ const fn decrypt_word(key: u32, value: u32) -> u32 {
 const fn encrypt_word(key: u32, value: u32) -> u32 {
     key ^ value
 }
+
+/// Half words, like item quantities, are encrypted with the lower half of the key.
+const fn decrypt_half_word(key: u32, value: u16) -> u16 {
+    (key as u16) ^ value
+}
+
+const fn encrypt_half_word(key: u32, value: u16) -> u16 {
+    (key as u16) ^ value
+}
+
+#[cfg(test)]
+mod test_helpers;
+#[cfg(test)]
+mod tests;
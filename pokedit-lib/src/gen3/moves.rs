@@ -0,0 +1,387 @@
+//! Move data, indexed by move ID.
+
+/// Name and base PP of every move, move 0 is used for empty move slots.
+const MOVES: [(&str, u8); COUNT] = [
+    ("---", 0),
+    ("Pound", 35),
+    ("Karate Chop", 25),
+    ("Double Slap", 10),
+    ("Comet Punch", 15),
+    ("Mega Punch", 20),
+    ("Pay Day", 20),
+    ("Fire Punch", 15),
+    ("Ice Punch", 15),
+    ("Thunder Punch", 15),
+    ("Scratch", 35),
+    ("Vice Grip", 30),
+    ("Guillotine", 5),
+    ("Razor Wind", 10),
+    ("Swords Dance", 30),
+    ("Cut", 30),
+    ("Gust", 35),
+    ("Wing Attack", 35),
+    ("Whirlwind", 20),
+    ("Fly", 15),
+    ("Bind", 20),
+    ("Slam", 20),
+    ("Vine Whip", 10),
+    ("Stomp", 20),
+    ("Double Kick", 30),
+    ("Mega Kick", 5),
+    ("Jump Kick", 25),
+    ("Rolling Kick", 15),
+    ("Sand Attack", 15),
+    ("Headbutt", 15),
+    ("Horn Attack", 25),
+    ("Fury Attack", 20),
+    ("Horn Drill", 5),
+    ("Tackle", 35),
+    ("Body Slam", 15),
+    ("Wrap", 20),
+    ("Take Down", 20),
+    ("Thrash", 20),
+    ("Double-Edge", 15),
+    ("Tail Whip", 30),
+    ("Poison Sting", 35),
+    ("Twineedle", 20),
+    ("Pin Missile", 20),
+    ("Leer", 30),
+    ("Bite", 25),
+    ("Growl", 40),
+    ("Roar", 20),
+    ("Sing", 15),
+    ("Supersonic", 20),
+    ("Sonic Boom", 20),
+    ("Disable", 20),
+    ("Acid", 30),
+    ("Ember", 25),
+    ("Flamethrower", 15),
+    ("Mist", 30),
+    ("Water Gun", 25),
+    ("Hydro Pump", 5),
+    ("Surf", 15),
+    ("Ice Beam", 10),
+    ("Blizzard", 5),
+    ("Psybeam", 20),
+    ("Bubble Beam", 20),
+    ("Aurora Beam", 20),
+    ("Hyper Beam", 5),
+    ("Peck", 35),
+    ("Drill Peck", 20),
+    ("Submission", 25),
+    ("Low Kick", 20),
+    ("Counter", 20),
+    ("Seismic Toss", 20),
+    ("Strength", 15),
+    ("Absorb", 20),
+    ("Mega Drain", 10),
+    ("Leech Seed", 10),
+    ("Growth", 40),
+    ("Razor Leaf", 25),
+    ("Solar Beam", 10),
+    ("Poison Powder", 35),
+    ("Stun Spore", 30),
+    ("Sleep Powder", 15),
+    ("Petal Dance", 20),
+    ("String Shot", 40),
+    ("Dragon Rage", 10),
+    ("Fire Spin", 15),
+    ("Thunder Shock", 30),
+    ("Thunderbolt", 15),
+    ("Thunder Wave", 20),
+    ("Thunder", 10),
+    ("Rock Throw", 15),
+    ("Earthquake", 10),
+    ("Fissure", 5),
+    ("Dig", 10),
+    ("Toxic", 10),
+    ("Confusion", 25),
+    ("Psychic", 10),
+    ("Hypnosis", 20),
+    ("Meditate", 40),
+    ("Agility", 30),
+    ("Quick Attack", 30),
+    ("Rage", 20),
+    ("Teleport", 20),
+    ("Night Shade", 15),
+    ("Mimic", 10),
+    ("Screech", 40),
+    ("Double Team", 15),
+    ("Recover", 20),
+    ("Harden", 30),
+    ("Minimize", 20),
+    ("Smokescreen", 20),
+    ("Confuse Ray", 10),
+    ("Withdraw", 40),
+    ("Defense Curl", 40),
+    ("Barrier", 30),
+    ("Light Screen", 30),
+    ("Haze", 30),
+    ("Reflect", 20),
+    ("Focus Energy", 30),
+    ("Bide", 10),
+    ("Metronome", 10),
+    ("Mirror Move", 20),
+    ("Self-Destruct", 5),
+    ("Egg Bomb", 10),
+    ("Lick", 30),
+    ("Smog", 20),
+    ("Sludge", 20),
+    ("Bone Club", 20),
+    ("Fire Blast", 5),
+    ("Waterfall", 15),
+    ("Clamp", 10),
+    ("Swift", 20),
+    ("Skull Bash", 15),
+    ("Spike Cannon", 15),
+    ("Constrict", 35),
+    ("Amnesia", 20),
+    ("Kinesis", 15),
+    ("Soft-Boiled", 10),
+    ("High Jump Kick", 20),
+    ("Glare", 30),
+    ("Dream Eater", 15),
+    ("Poison Gas", 40),
+    ("Barrage", 20),
+    ("Leech Life", 15),
+    ("Lovely Kiss", 10),
+    ("Sky Attack", 5),
+    ("Transform", 10),
+    ("Bubble", 30),
+    ("Dizzy Punch", 10),
+    ("Spore", 15),
+    ("Flash", 20),
+    ("Psywave", 15),
+    ("Splash", 40),
+    ("Acid Armor", 40),
+    ("Crabhammer", 10),
+    ("Explosion", 5),
+    ("Fury Swipes", 15),
+    ("Bonemerang", 10),
+    ("Rest", 10),
+    ("Rock Slide", 10),
+    ("Hyper Fang", 15),
+    ("Sharpen", 30),
+    ("Conversion", 30),
+    ("Tri Attack", 10),
+    ("Super Fang", 10),
+    ("Slash", 20),
+    ("Substitute", 10),
+    ("Struggle", 1),
+    ("Sketch", 1),
+    ("Triple Kick", 10),
+    ("Thief", 10),
+    ("Spider Web", 10),
+    ("Mind Reader", 5),
+    ("Nightmare", 15),
+    ("Flame Wheel", 25),
+    ("Snore", 15),
+    ("Curse", 10),
+    ("Flail", 15),
+    ("Conversion 2", 30),
+    ("Aeroblast", 5),
+    ("Cotton Spore", 40),
+    ("Reversal", 15),
+    ("Spite", 10),
+    ("Powder Snow", 25),
+    ("Protect", 10),
+    ("Mach Punch", 30),
+    ("Scary Face", 10),
+    ("Faint Attack", 20),
+    ("Sweet Kiss", 10),
+    ("Belly Drum", 10),
+    ("Sludge Bomb", 10),
+    ("Mud-Slap", 10),
+    ("Octazooka", 10),
+    ("Spikes", 20),
+    ("Zap Cannon", 5),
+    ("Foresight", 40),
+    ("Destiny Bond", 5),
+    ("Perish Song", 5),
+    ("Icy Wind", 15),
+    ("Detect", 5),
+    ("Bone Rush", 10),
+    ("Lock-On", 5),
+    ("Outrage", 15),
+    ("Sandstorm", 10),
+    ("Giga Drain", 5),
+    ("Endure", 10),
+    ("Charm", 20),
+    ("Rollout", 20),
+    ("False Swipe", 40),
+    ("Swagger", 15),
+    ("Milk Drink", 10),
+    ("Spark", 20),
+    ("Fury Cutter", 20),
+    ("Steel Wing", 25),
+    ("Mean Look", 5),
+    ("Attract", 15),
+    ("Sleep Talk", 10),
+    ("Heal Bell", 5),
+    ("Return", 20),
+    ("Present", 15),
+    ("Frustration", 20),
+    ("Safeguard", 25),
+    ("Pain Split", 20),
+    ("Sacred Fire", 5),
+    ("Magnitude", 30),
+    ("Dynamic Punch", 5),
+    ("Megahorn", 10),
+    ("Dragon Breath", 20),
+    ("Baton Pass", 40),
+    ("Encore", 5),
+    ("Pursuit", 20),
+    ("Rapid Spin", 40),
+    ("Sweet Scent", 20),
+    ("Iron Tail", 15),
+    ("Metal Claw", 35),
+    ("Vital Throw", 10),
+    ("Morning Sun", 5),
+    ("Synthesis", 5),
+    ("Moonlight", 5),
+    ("Hidden Power", 15),
+    ("Cross Chop", 5),
+    ("Twister", 20),
+    ("Rain Dance", 5),
+    ("Sunny Day", 5),
+    ("Crunch", 15),
+    ("Mirror Coat", 20),
+    ("Psych Up", 10),
+    ("Extreme Speed", 5),
+    ("Ancient Power", 5),
+    ("Shadow Ball", 15),
+    ("Future Sight", 15),
+    ("Rock Smash", 15),
+    ("Whirlpool", 15),
+    ("Beat Up", 10),
+    ("Fake Out", 10),
+    ("Uproar", 10),
+    ("Stockpile", 20),
+    ("Spit Up", 10),
+    ("Swallow", 10),
+    ("Heat Wave", 10),
+    ("Hail", 10),
+    ("Torment", 15),
+    ("Flatter", 15),
+    ("Will-O-Wisp", 15),
+    ("Memento", 10),
+    ("Facade", 20),
+    ("Focus Punch", 20),
+    ("Smelling Salt", 10),
+    ("Follow Me", 20),
+    ("Nature Power", 20),
+    ("Charge", 20),
+    ("Taunt", 20),
+    ("Helping Hand", 20),
+    ("Trick", 10),
+    ("Role Play", 10),
+    ("Wish", 10),
+    ("Assist", 20),
+    ("Ingrain", 20),
+    ("Superpower", 5),
+    ("Magic Coat", 15),
+    ("Recycle", 10),
+    ("Revenge", 10),
+    ("Brick Break", 15),
+    ("Yawn", 10),
+    ("Knock Off", 20),
+    ("Endeavor", 5),
+    ("Eruption", 5),
+    ("Skill Swap", 10),
+    ("Imprison", 10),
+    ("Refresh", 20),
+    ("Grudge", 5),
+    ("Snatch", 10),
+    ("Secret Power", 20),
+    ("Dive", 10),
+    ("Arm Thrust", 20),
+    ("Camouflage", 20),
+    ("Tail Glow", 20),
+    ("Luster Purge", 5),
+    ("Mist Ball", 5),
+    ("Feather Dance", 15),
+    ("Teeter Dance", 20),
+    ("Blaze Kick", 10),
+    ("Mud Sport", 15),
+    ("Ice Ball", 20),
+    ("Needle Arm", 15),
+    ("Slack Off", 10),
+    ("Hyper Voice", 10),
+    ("Poison Fang", 15),
+    ("Crush Claw", 10),
+    ("Blast Burn", 5),
+    ("Hydro Cannon", 5),
+    ("Meteor Mash", 10),
+    ("Astonish", 15),
+    ("Weather Ball", 10),
+    ("Aromatherapy", 5),
+    ("Fake Tears", 20),
+    ("Air Cutter", 25),
+    ("Overheat", 5),
+    ("Odor Sleuth", 40),
+    ("Rock Tomb", 10),
+    ("Silver Wind", 5),
+    ("Metal Sound", 40),
+    ("Grass Whistle", 15),
+    ("Tickle", 20),
+    ("Cosmic Power", 20),
+    ("Water Spout", 5),
+    ("Signal Beam", 15),
+    ("Shadow Punch", 20),
+    ("Extrasensory", 30),
+    ("Sky Uppercut", 15),
+    ("Sand Tomb", 15),
+    ("Sheer Cold", 5),
+    ("Muddy Water", 10),
+    ("Bullet Seed", 30),
+    ("Aerial Ace", 20),
+    ("Icicle Spear", 30),
+    ("Iron Defense", 15),
+    ("Block", 5),
+    ("Howl", 40),
+    ("Dragon Claw", 15),
+    ("Frenzy Plant", 5),
+    ("Bulk Up", 20),
+    ("Bounce", 5),
+    ("Mud Shot", 15),
+    ("Poison Tail", 25),
+    ("Covet", 40),
+    ("Volt Tackle", 15),
+    ("Magical Leaf", 20),
+    ("Water Sport", 15),
+    ("Calm Mind", 20),
+    ("Leaf Blade", 15),
+    ("Dragon Dance", 20),
+    ("Rock Blast", 10),
+    ("Shock Wave", 20),
+    ("Water Pulse", 20),
+    ("Doom Desire", 5),
+    ("Psycho Boost", 5),
+];
+
+/// Number of move IDs, including the empty move 0.
+pub const COUNT: usize = 355;
+
+/// Cut, Fly, Surf, Strength, Flash, Rock Smash, Waterfall and Dive.
+pub const HMS: [u16; 8] = [15, 19, 57, 70, 148, 249, 127, 291];
+
+/// Returns whether the ID belongs to a move, including the empty move 0.
+pub fn is_valid(move_id: u16) -> bool {
+    usize::from(move_id) < COUNT
+}
+
+/// Returns the name of the move, if the ID is valid.
+pub fn name(move_id: u16) -> Option<&'static str> {
+    MOVES.get(move_id as usize).map(|(name, _)| *name)
+}
+
+/// Returns the PP of the move without any PP Ups applied, if the ID is valid.
+pub fn base_pp(move_id: u16) -> Option<u8> {
+    MOVES.get(move_id as usize).map(|(_, pp)| *pp)
+}
+
+/// Returns the PP of the move after applying `pp_ups` (0 to 3) PP Ups, each adding a fifth of the
+/// base PP.
+pub fn max_pp(move_id: u16, pp_ups: u8) -> Option<u8> {
+    base_pp(move_id).map(|pp| pp + pp * pp_ups.min(3) / 5)
+}
@@ -0,0 +1,389 @@
+use std::collections::HashMap;
+
+use log::error;
+
+use crate::{
+    error::{PkError, PkErrorLoad},
+    mem::le as mem,
+    PkResult,
+};
+
+use super::{
+    check_index,
+    pokemon::{DecryptedPokemon, PokemonData},
+    text, Data, DataMut, DataView, SaveSlot, Section,
+};
+
+/// The PC storage system is split across sections 5 to 13, which aren't necessarily contiguous in
+/// the save file, so it is read through the slices of every section.
+///
+/// # Data
+///
+/// Once all the sections are concatenated, the contents are as follows
+///
+/// | Offset | Size | Contents |
+/// |--------|------|----------|
+/// | 0x0000 | 4 | Current box |
+/// | 0x0004 | 33600 | 420 Pokemon (14 boxes of 30) |
+/// | 0x8344 | 126 | Box names (14 names of 9 bytes) |
+/// | 0x83C2 | 14 | Box wallpapers |
+#[derive(Debug, Clone, Copy)]
+pub struct PCStorage<'d> {
+    sections: [&'d [u8]; PCStorage::SECTION_COUNT],
+}
+
+#[derive(Debug)]
+pub struct PCStorageMut<'d> {
+    sections: [&'d mut [u8]; PCStorage::SECTION_COUNT],
+}
+
+/// A copy of a single PC box, read with [`PCStorage::pc_box`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PCBox {
+    name: [u8; PCStorage::BOX_NAME_LENGTH],
+    pokemon: [[u8; PCStorage::POKEMON_SIZE]; PCStorage::BOX_SIZE],
+}
+
+impl PCBox {
+    /// Returns the box name decoded from the game's charset.
+    pub fn name(&self) -> String {
+        text::decode(&self.name)
+    }
+
+    /// Returns the decrypted Pokemon in the given slot, or `None` if the slot is empty or out of
+    /// bounds.
+    pub fn pokemon(&self, slot: usize) -> Option<DecryptedPokemon> {
+        let raw = self.pokemon.get(slot)?;
+        Data::<PokemonData>::new(raw)
+            .has_species()
+            .then(|| DecryptedPokemon::decrypt(raw))
+    }
+}
+
+impl PCStorage<'_> {
+    pub const FIRST_SECTION_ID: u16 = 5;
+    pub const SECTION_COUNT: usize = 9;
+    /// Bytes of PC data stored in every section but the last one.
+    pub const SECTION_DATA_SIZE: usize = Section::DATA_SIZE;
+    pub const SIZE: usize = 0x83D0;
+
+    pub const BOX_COUNT: usize = 14;
+    pub const BOX_SIZE: usize = 30;
+    pub const POKEMON_SIZE: usize = PokemonData::SIZE;
+
+    pub const CURRENT_BOX_OFFSET: usize = 0x0000;
+    pub const POKEMON_OFFSET: usize = 0x0004;
+    pub const BOX_NAMES_OFFSET: usize = 0x8344;
+    pub const BOX_NAME_LENGTH: usize = 9;
+    pub const WALLPAPERS_OFFSET: usize = 0x83C2;
+
+    pub const PKMDB_MAGIC: [u8; 4] = *b"PKMD";
+    pub const PKMDB_VERSION: u16 = 1;
+    const PKMDB_HEADER_SIZE: usize = 10;
+    /// Pokemon are stored with the 20 bytes of party stats, which are zeroed for box Pokemon.
+    const PKMDB_POKEMON_SIZE: usize = 100;
+    const PKMDB_ENTRY_SIZE: usize = 4 + Self::PKMDB_POKEMON_SIZE;
+
+    /// Returns the number of bytes of PC data stored in the nth PC section.
+    const fn section_data_size(index: usize) -> usize {
+        if index == Self::SECTION_COUNT - 1 {
+            Self::SIZE - Self::SECTION_DATA_SIZE * (Self::SECTION_COUNT - 1)
+        } else {
+            Self::SECTION_DATA_SIZE
+        }
+    }
+
+    fn pokemon_offset(box_index: usize, slot: usize) -> usize {
+        Self::POKEMON_OFFSET + (box_index * Self::BOX_SIZE + slot) * Self::POKEMON_SIZE
+    }
+
+    fn check_box_index(box_index: usize) -> PkResult<()> {
+        check_index("box", box_index, Self::BOX_COUNT)
+    }
+
+    fn check_slot_index(slot: usize) -> PkResult<()> {
+        check_index("box slot", slot, Self::BOX_SIZE)
+    }
+}
+
+impl<'d> PCStorage<'d> {
+    pub fn new(save_slot: Data<'d, SaveSlot>) -> PkResult<Self> {
+        let mut sections = [None; PCStorage::SECTION_COUNT];
+        for section in save_slot.sections() {
+            if let Some(index) = pc_section_index(section.id()) {
+                sections[index] = Some(&section.data[..PCStorage::section_data_size(index)]);
+            }
+        }
+
+        Ok(Self {
+            sections: unwrap_sections(sections)?,
+        })
+    }
+
+    pub fn current_box(&self) -> u8 {
+        let mut current_box = [0];
+        self.read(PCStorage::CURRENT_BOX_OFFSET, &mut current_box);
+        current_box[0]
+    }
+
+    /// Returns the raw (encrypted) bytes of the Pokemon in the given box slot.
+    pub fn pokemon_raw(
+        &self,
+        box_index: usize,
+        slot: usize,
+    ) -> PkResult<[u8; PCStorage::POKEMON_SIZE]> {
+        PCStorage::check_box_index(box_index)?;
+        PCStorage::check_slot_index(slot)?;
+
+        let mut pokemon = [0; PCStorage::POKEMON_SIZE];
+        self.read(PCStorage::pokemon_offset(box_index, slot), &mut pokemon);
+        Ok(pokemon)
+    }
+
+    /// Returns a copy of the given box, with its name and Pokemon.
+    pub fn pc_box(&self, box_index: usize) -> PkResult<PCBox> {
+        PCStorage::check_box_index(box_index)?;
+
+        let mut name = [0; PCStorage::BOX_NAME_LENGTH];
+        self.read(
+            PCStorage::BOX_NAMES_OFFSET + box_index * PCStorage::BOX_NAME_LENGTH,
+            &mut name,
+        );
+        let mut pokemon = [[0; PCStorage::POKEMON_SIZE]; PCStorage::BOX_SIZE];
+        for (slot, pokemon) in pokemon.iter_mut().enumerate() {
+            self.read(PCStorage::pokemon_offset(box_index, slot), pokemon);
+        }
+
+        Ok(PCBox { name, pokemon })
+    }
+
+    /// Returns the raw (encrypted) bytes of every slot of the box, empty slots are returned as is.
+    pub fn export_box(&self, box_index: usize) -> PkResult<Vec<[u8; PCStorage::POKEMON_SIZE]>> {
+        PCStorage::check_box_index(box_index)?;
+
+        (0..PCStorage::BOX_SIZE)
+            .map(|slot| self.pokemon_raw(box_index, slot))
+            .collect()
+    }
+
+    /// Exports every Pokemon in the PC in the `.pkmdb` format.
+    ///
+    /// | Offset | Size | Contents |
+    /// |--------|------|----------|
+    /// | 0x00 | 4 | Magic `PKMD` |
+    /// | 0x04 | 2 | Version |
+    /// | 0x06 | 4 | Pokemon count |
+    /// | 0x0A | 104 * count | Box index (2 bytes), slot (2 bytes) and Pokemon (100 bytes) |
+    pub fn export_pkmdb(&self) -> Vec<u8> {
+        let entries = self.stored_pokemon().collect::<Vec<_>>();
+
+        let mut bytes =
+            vec![0; PCStorage::PKMDB_HEADER_SIZE + entries.len() * PCStorage::PKMDB_ENTRY_SIZE];
+        bytes[0..4].copy_from_slice(&PCStorage::PKMDB_MAGIC);
+        mem::write_half_word(&mut bytes, 4, PCStorage::PKMDB_VERSION);
+        mem::write_word(&mut bytes, 6, entries.len() as u32);
+
+        for (entry, (box_index, slot, pokemon)) in bytes[PCStorage::PKMDB_HEADER_SIZE..]
+            .chunks_exact_mut(PCStorage::PKMDB_ENTRY_SIZE)
+            .zip(entries)
+        {
+            mem::write_half_word(entry, 0, box_index as u16);
+            mem::write_half_word(entry, 2, slot as u16);
+            entry[4..(4 + PCStorage::POKEMON_SIZE)].copy_from_slice(&pokemon);
+        }
+
+        bytes
+    }
+
+    /// Returns how many Pokemon of every species are stored in the PC. Eggs and corrupted Pokemon
+    /// are not counted.
+    pub fn pokemon_count_by_species(&self) -> HashMap<u16, u32> {
+        let mut counts = HashMap::new();
+        for (_, _, raw) in self.stored_pokemon() {
+            let pokemon = DecryptedPokemon::decrypt(&raw);
+            if pokemon.is_egg() || !pokemon.is_checksum_valid() {
+                continue;
+            }
+            *counts.entry(pokemon.species()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Returns the `n` species with the most Pokemon in the PC, most common first. Ties are
+    /// ordered by species ID.
+    pub fn most_common_species(&self, n: usize) -> Vec<(u16, u32)> {
+        let mut counts = self
+            .pokemon_count_by_species()
+            .into_iter()
+            .collect::<Vec<_>>();
+        counts.sort_by(|(a_species, a_count), (b_species, b_count)| {
+            b_count.cmp(a_count).then(a_species.cmp(b_species))
+        });
+        counts.truncate(n);
+        counts
+    }
+
+    /// Returns the box index, slot and raw (encrypted) bytes of every non-empty slot in the PC,
+    /// box by box.
+    pub(super) fn stored_pokemon(
+        &self,
+    ) -> impl Iterator<Item = (usize, usize, [u8; PCStorage::POKEMON_SIZE])> + '_ {
+        (0..PCStorage::BOX_COUNT)
+            .flat_map(|box_index| (0..PCStorage::BOX_SIZE).map(move |slot| (box_index, slot)))
+            .map(|(box_index, slot)| {
+                let mut pokemon = [0; PCStorage::POKEMON_SIZE];
+                self.read(PCStorage::pokemon_offset(box_index, slot), &mut pokemon);
+                (box_index, slot, pokemon)
+            })
+            .filter(|(_, _, pokemon)| Data::<PokemonData>::new(pokemon).has_species())
+    }
+
+    /// Fills `buffer` with the contents of the PC storage starting at `offset`.
+    fn read(&self, mut offset: usize, mut buffer: &mut [u8]) {
+        while !buffer.is_empty() {
+            let section = self.sections[offset / PCStorage::SECTION_DATA_SIZE];
+            let start = offset % PCStorage::SECTION_DATA_SIZE;
+            let len = buffer.len().min(section.len() - start);
+            buffer[..len].copy_from_slice(&section[start..(start + len)]);
+            buffer = &mut buffer[len..];
+            offset += len;
+        }
+    }
+}
+
+impl<'d> PCStorageMut<'d> {
+    pub fn new(save_slot: DataMut<'d, SaveSlot>) -> PkResult<Self> {
+        let mut sections: [Option<&'d mut [u8]>; PCStorage::SECTION_COUNT] = Default::default();
+        for section in save_slot.data.chunks_exact_mut(Section::SIZE) {
+            let id = mem::read_half_word(section, Section::SECTION_ID_OFFSET);
+            if let Some(index) = pc_section_index(id) {
+                sections[index] = Some(&mut section[..PCStorage::section_data_size(index)]);
+            }
+        }
+
+        Ok(Self {
+            sections: unwrap_sections(sections)?,
+        })
+    }
+
+    pub fn as_storage(&self) -> PCStorage<'_> {
+        PCStorage {
+            sections: self.sections.each_ref().map(|section| &**section),
+        }
+    }
+
+    /// Overwrites the Pokemon in the given box slot with the raw (encrypted) bytes.
+    pub fn set_pokemon_raw(
+        &mut self,
+        box_index: usize,
+        slot: usize,
+        pokemon: &[u8; PCStorage::POKEMON_SIZE],
+    ) -> PkResult<()> {
+        PCStorage::check_box_index(box_index)?;
+        PCStorage::check_slot_index(slot)?;
+
+        self.write(PCStorage::pokemon_offset(box_index, slot), pokemon);
+        Ok(())
+    }
+
+    /// Overwrites every slot of the box with the given raw (encrypted) Pokemon.
+    pub fn import_box(
+        &mut self,
+        box_index: usize,
+        pokemon: &[[u8; PCStorage::POKEMON_SIZE]; PCStorage::BOX_SIZE],
+    ) -> PkResult<()> {
+        PCStorage::check_box_index(box_index)?;
+
+        for (slot, pokemon) in pokemon.iter().enumerate() {
+            self.write(PCStorage::pokemon_offset(box_index, slot), pokemon);
+        }
+
+        Ok(())
+    }
+
+    /// Copies the Pokemon in the source slot as is, including its personality value and OT, to
+    /// the destination slot, which must be empty.
+    ///
+    /// Clones are identical to the original Pokemon, so trade systems that check for duplicates
+    /// may reject them.
+    pub fn clone_pokemon(
+        &mut self,
+        src_box: usize,
+        src_slot: usize,
+        dst_box: usize,
+        dst_slot: usize,
+    ) -> PkResult<()> {
+        let storage = self.as_storage();
+        let pokemon = storage.pokemon_raw(src_box, src_slot)?;
+        let destination = storage.pokemon_raw(dst_box, dst_slot)?;
+        if Data::<PokemonData>::new(&destination).has_species() {
+            return Err(PkError::InvalidData("destination occupied"));
+        }
+
+        self.write(PCStorage::pokemon_offset(dst_box, dst_slot), &pokemon);
+        Ok(())
+    }
+
+    /// Imports the Pokemon of a `.pkmdb` file (see [`PCStorage::export_pkmdb`]) into their box
+    /// slots, returning how many were imported. Nothing is written if the file is invalid.
+    pub fn import_pkmdb(&mut self, data: &[u8]) -> PkResult<usize> {
+        if data.len() < PCStorage::PKMDB_HEADER_SIZE || data[0..4] != PCStorage::PKMDB_MAGIC {
+            return Err(PkError::InvalidData("pkmdb magic"));
+        }
+        if mem::read_half_word(data, 4) != PCStorage::PKMDB_VERSION {
+            return Err(PkError::InvalidData("pkmdb version"));
+        }
+
+        let count = mem::read_word(data, 6) as usize;
+        let entries = &data[PCStorage::PKMDB_HEADER_SIZE..];
+        if count.checked_mul(PCStorage::PKMDB_ENTRY_SIZE) != Some(entries.len()) {
+            return Err(PkError::InvalidData("pkmdb pokemon count"));
+        }
+
+        let entries = entries
+            .chunks_exact(PCStorage::PKMDB_ENTRY_SIZE)
+            .map(|entry| {
+                let box_index = mem::read_half_word(entry, 0) as usize;
+                let slot = mem::read_half_word(entry, 2) as usize;
+                PCStorage::check_box_index(box_index)?;
+                PCStorage::check_slot_index(slot)?;
+                Ok((box_index, slot, &entry[4..(4 + PCStorage::POKEMON_SIZE)]))
+            })
+            .collect::<PkResult<Vec<_>>>()?;
+
+        for (box_index, slot, pokemon) in entries {
+            self.write(PCStorage::pokemon_offset(box_index, slot), pokemon);
+        }
+
+        Ok(count)
+    }
+
+    /// Writes `bytes` into the PC storage starting at `offset`.
+    fn write(&mut self, mut offset: usize, mut bytes: &[u8]) {
+        while !bytes.is_empty() {
+            let section = &mut self.sections[offset / PCStorage::SECTION_DATA_SIZE];
+            let start = offset % PCStorage::SECTION_DATA_SIZE;
+            let len = bytes.len().min(section.len() - start);
+            section[start..(start + len)].copy_from_slice(&bytes[..len]);
+            bytes = &bytes[len..];
+            offset += len;
+        }
+    }
+}
+
+/// Returns the position of the section within the PC storage, if it is part of it.
+fn pc_section_index(id: u16) -> Option<usize> {
+    let index = id.checked_sub(PCStorage::FIRST_SECTION_ID)? as usize;
+    (index < PCStorage::SECTION_COUNT).then_some(index)
+}
+
+fn unwrap_sections<T>(
+    sections: [Option<T>; PCStorage::SECTION_COUNT],
+) -> PkResult<[T; PCStorage::SECTION_COUNT]> {
+    if sections.iter().any(Option::is_none) {
+        error!("save slot is missing a PC buffer section");
+        return Err(PkError::Load(PkErrorLoad::MissingSection("PC buffer")));
+    }
+
+    Ok(sections.map(Option::unwrap))
+}
@@ -0,0 +1,1057 @@
+//! Pokemon data structures.
+
+use crate::mem::le as mem;
+
+use crate::{
+    common::{Nature, StatKind},
+    error::PkError,
+    PkResult,
+};
+
+use super::{
+    check_index, items::ItemId, moves, species, Data, DataMut, DataView, Gender, Language,
+    TrainerId,
+};
+
+/// A Pokemon as stored in the PC.
+///
+/// # Data
+///
+/// Every Pokemon is stored as an 80 byte structure, party Pokemon append another 20 bytes with
+/// their calculated stats.
+///
+/// | Offset | Size | Contents |
+/// |--------|------|----------|
+/// | 0x00 | 4 | Personality value |
+/// | 0x04 | 4 | Original trainer ID |
+/// | 0x08 | 10 | Nickname |
+/// | 0x12 | 1 | Language |
+/// | 0x13 | 1 | Flags |
+/// | 0x14 | 7 | Original trainer name |
+/// | 0x1B | 1 | Markings |
+/// | 0x1C | 2 | Checksum |
+/// | 0x1E | 2 | Unused |
+/// | 0x20 | 48 | Encrypted substructures |
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PokemonData;
+
+impl DataView for PokemonData {
+    const SIZE: usize = 80;
+}
+
+impl PokemonData {
+    pub const PERSONALITY_OFFSET: usize = 0x00;
+    pub const OT_ID_OFFSET: usize = 0x04;
+    pub const PUBLIC_OT_ID_OFFSET: usize = Self::OT_ID_OFFSET;
+    pub const PRIVATE_OT_ID_OFFSET: usize = Self::OT_ID_OFFSET + 2;
+    pub const NICKNAME_OFFSET: usize = 0x08;
+    pub const NICKNAME_LENGTH: usize = 10;
+    pub const LANGUAGE_OFFSET: usize = 0x12;
+    pub const FLAGS_OFFSET: usize = 0x13;
+    pub const OT_NAME_OFFSET: usize = 0x14;
+    pub const OT_NAME_LENGTH: usize = 7;
+    pub const CHECKSUM_OFFSET: usize = 0x1C;
+    pub const SUBSTRUCTURES_OFFSET: usize = 0x20;
+
+    /// Size of the four 12 byte substructures.
+    pub const SUBSTRUCTURES_SIZE: usize = 48;
+
+    pub const MALE_ONLY_RATIO: u8 = 0;
+    pub const FEMALE_ONLY_RATIO: u8 = 254;
+    pub const GENDERLESS_RATIO: u8 = 255;
+
+    /// Set by the game for every slot holding a Pokemon.
+    pub const HAS_SPECIES_FLAG: u8 = 1 << 1;
+}
+
+impl<'d> Data<'d, PokemonData> {
+    pub fn personality(self) -> u32 {
+        mem::read_word(self.data, PokemonData::PERSONALITY_OFFSET)
+    }
+
+    pub fn ot_id(self) -> TrainerId {
+        TrainerId {
+            public: mem::read_half_word(self.data, PokemonData::PUBLIC_OT_ID_OFFSET),
+            private: mem::read_half_word(self.data, PokemonData::PRIVATE_OT_ID_OFFSET),
+        }
+    }
+
+    /// Returns whether the Pokemon is shiny, see [`is_shiny`].
+    pub fn is_shiny(self) -> bool {
+        is_shiny(self.personality(), self.ot_id())
+    }
+
+    /// Returns the nickname, which isn't encrypted, see [`super::text::decode`].
+    pub fn nickname_raw(self) -> [u8; PokemonData::NICKNAME_LENGTH] {
+        self.data[PokemonData::NICKNAME_OFFSET
+            ..(PokemonData::NICKNAME_OFFSET + PokemonData::NICKNAME_LENGTH)]
+            .try_into()
+            .unwrap()
+    }
+
+    /// Returns the language of the game the Pokemon was caught in, if it was tracked.
+    pub fn language(self) -> PkResult<Option<Language>> {
+        Language::from_pokemon_language(self.data[PokemonData::LANGUAGE_OFFSET])
+    }
+
+    /// Returns whether the slot holds a Pokemon, which can be known without decrypting it.
+    pub fn has_species(self) -> bool {
+        self.data[PokemonData::FLAGS_OFFSET] & PokemonData::HAS_SPECIES_FLAG != 0
+    }
+
+    /// Returns the gender of the Pokemon given the gender ratio of its species. Species with a
+    /// ratio of 255 are genderless, 254 always female and 0 always male, otherwise the Pokemon is
+    /// female when the lowest byte of its personality value is below the ratio.
+    pub fn apparent_gender(self, gender_ratio: u8) -> Gender {
+        match gender_ratio {
+            PokemonData::GENDERLESS_RATIO => Gender::Genderless,
+            PokemonData::FEMALE_ONLY_RATIO => Gender::Female,
+            PokemonData::MALE_ONLY_RATIO => Gender::Male,
+            ratio if (self.personality() as u8) < ratio => Gender::Female,
+            _ => Gender::Male,
+        }
+    }
+
+    /// Returns a copy of the Pokemon with its substructures decrypted and unshuffled.
+    pub fn decrypt(self) -> DecryptedPokemon {
+        let mut raw = [0; PokemonData::SIZE];
+        raw.copy_from_slice(self.data);
+        DecryptedPokemon::decrypt(&raw)
+    }
+}
+
+/// A Pokemon in the party, which is followed by its status and calculated stats.
+///
+/// # Data
+///
+/// | Offset | Size | Contents |
+/// |--------|------|----------|
+/// | 0x00 | 80 | Pokemon, see [`PokemonData`] |
+/// | 0x50 | 4 | Status condition |
+/// | 0x54 | 1 | Level |
+/// | 0x55 | 1 | Mail ID, 0xFF if not holding mail |
+/// | 0x56 | 2 | Current HP |
+/// | 0x58 | 2 | Max HP |
+/// | 0x5A | 2 | Attack |
+/// | 0x5C | 2 | Defense |
+/// | 0x5E | 2 | Speed |
+/// | 0x60 | 2 | Special attack |
+/// | 0x62 | 2 | Special defense |
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PartyPokemonData;
+
+impl DataView for PartyPokemonData {
+    const SIZE: usize = 100;
+}
+
+impl PartyPokemonData {
+    pub const STATUS_OFFSET: usize = 0x50;
+    pub const LEVEL_OFFSET: usize = 0x54;
+    pub const MAIL_OFFSET: usize = 0x55;
+    pub const CURRENT_HP_OFFSET: usize = 0x56;
+    pub const MAX_HP_OFFSET: usize = 0x58;
+    /// Attack, defense, speed, special attack and special defense follow the max HP.
+    pub const STATS_OFFSET: usize = 0x5A;
+
+    pub const NO_MAIL: u8 = 0xFF;
+}
+
+impl<'d> DataMut<'d, PokemonData> {
+    /// Encrypts the Pokemon and writes it over the current one.
+    pub fn set_pokemon(&mut self, pokemon: &DecryptedPokemon) {
+        self.data.copy_from_slice(&pokemon.encrypt());
+    }
+}
+
+impl<'d> Data<'d, PartyPokemonData> {
+    pub fn pokemon(self) -> Data<'d, PokemonData> {
+        Data::new(self.data)
+    }
+
+    pub fn status(self) -> StatusCondition {
+        StatusCondition::new(mem::read_word(self.data, PartyPokemonData::STATUS_OFFSET))
+    }
+
+    pub fn level(self) -> u8 {
+        self.data[PartyPokemonData::LEVEL_OFFSET]
+    }
+
+    pub fn current_hp(self) -> u16 {
+        mem::read_half_word(self.data, PartyPokemonData::CURRENT_HP_OFFSET)
+    }
+
+    pub fn max_hp(self) -> u16 {
+        mem::read_half_word(self.data, PartyPokemonData::MAX_HP_OFFSET)
+    }
+
+    /// Returns the Pokemon in the `.pk3` format used by PKHeX, which stores the substructures
+    /// decrypted and unshuffled, followed by the party stats.
+    pub fn to_pk3(self) -> [u8; PartyPokemonData::SIZE] {
+        let mut pk3 = [0; PartyPokemonData::SIZE];
+        pk3[..PokemonData::SIZE].copy_from_slice(&self.pokemon().decrypt().to_bytes());
+        pk3[PokemonData::SIZE..].copy_from_slice(&self.data[PokemonData::SIZE..]);
+        pk3
+    }
+}
+
+impl<'d> DataMut<'d, PartyPokemonData> {
+    /// Encrypts the Pokemon and writes it over the current one, leaving the party stats as is.
+    pub fn set_pokemon(&mut self, pokemon: &DecryptedPokemon) {
+        self.data[..PokemonData::SIZE].copy_from_slice(&pokemon.encrypt());
+    }
+
+    /// Encrypts the Pokemon and writes it over the current one, recalculating the party stats
+    /// from it like [`DecryptedPokemon::to_party_bytes`]: the level from its experience, the stats
+    /// from its base stats, full HP, no status condition and no mail. Nothing is written if the
+    /// species is unknown.
+    pub fn set_pokemon_with_stats(&mut self, pokemon: &DecryptedPokemon) -> PkResult<()> {
+        let base_stats = species::Species::from(pokemon.species())
+            .base_stats()
+            .ok_or(PkError::InvalidData("species"))?;
+        self.data
+            .copy_from_slice(&pokemon.to_party_bytes(|_| base_stats));
+        Ok(())
+    }
+
+    /// Overwrites the Pokemon with a party `.pk3` file, see [`Data::to_pk3`]. Nothing is written
+    /// if the file has the wrong size or checksum.
+    pub fn set_from_pk3(&mut self, pk3: &[u8]) -> PkResult<()> {
+        if pk3.len() != PartyPokemonData::SIZE {
+            return Err(PkError::InvalidData("pk3 size"));
+        }
+        let mut bytes = [0; PokemonData::SIZE];
+        bytes.copy_from_slice(&pk3[..PokemonData::SIZE]);
+        let pokemon = DecryptedPokemon::from_bytes(bytes);
+        if !pokemon.is_checksum_valid() {
+            return Err(PkError::InvalidData("pk3 checksum"));
+        }
+
+        self.set_pokemon(&pokemon);
+        self.data[PokemonData::SIZE..].copy_from_slice(&pk3[PokemonData::SIZE..]);
+        Ok(())
+    }
+
+    pub fn set_status(&mut self, status: StatusCondition) {
+        mem::write_word(self.data, PartyPokemonData::STATUS_OFFSET, status.raw());
+    }
+
+    pub fn set_current_hp(&mut self, hp: u16) {
+        mem::write_half_word(self.data, PartyPokemonData::CURRENT_HP_OFFSET, hp);
+    }
+}
+
+/// The status condition of a party Pokemon.
+///
+/// | Bits | Contents |
+/// |------|----------|
+/// | 0-2 | Sleep turns remaining, asleep if not 0 |
+/// | 3 | Poisoned |
+/// | 4 | Burned |
+/// | 5 | Frozen |
+/// | 6 | Paralyzed |
+/// | 7 | Badly poisoned |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatusCondition(u32);
+
+impl StatusCondition {
+    const SLEEP_MASK: u32 = 0b111;
+    const POISON_BIT: u32 = 3;
+    const BURN_BIT: u32 = 4;
+    const FREEZE_BIT: u32 = 5;
+    const PARALYSIS_BIT: u32 = 6;
+    const BAD_POISON_BIT: u32 = 7;
+
+    pub const fn new(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    pub const fn raw(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the healthy status.
+    pub const fn cure(self) -> Self {
+        Self(0)
+    }
+
+    pub const fn is_ok(self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn is_sleep(self) -> bool {
+        self.sleep_turns_remaining() != 0
+    }
+
+    pub const fn sleep_turns_remaining(self) -> u8 {
+        (self.0 & Self::SLEEP_MASK) as u8
+    }
+
+    pub const fn is_poisoned(self) -> bool {
+        self.bit(Self::POISON_BIT)
+    }
+
+    pub const fn is_badly_poisoned(self) -> bool {
+        self.bit(Self::BAD_POISON_BIT)
+    }
+
+    pub const fn is_burned(self) -> bool {
+        self.bit(Self::BURN_BIT)
+    }
+
+    pub const fn is_frozen(self) -> bool {
+        self.bit(Self::FREEZE_BIT)
+    }
+
+    pub const fn is_paralyzed(self) -> bool {
+        self.bit(Self::PARALYSIS_BIT)
+    }
+
+    /// Puts the Pokemon to sleep for `turns` (at most 7) turns, 0 wakes it up.
+    pub fn set_sleep_turns_remaining(&mut self, turns: u8) {
+        self.0 = (self.0 & !Self::SLEEP_MASK) | (u32::from(turns.min(7)) & Self::SLEEP_MASK);
+    }
+
+    pub fn set_poisoned(&mut self, value: bool) {
+        self.set_bit(Self::POISON_BIT, value);
+    }
+
+    pub fn set_badly_poisoned(&mut self, value: bool) {
+        self.set_bit(Self::BAD_POISON_BIT, value);
+    }
+
+    pub fn set_burned(&mut self, value: bool) {
+        self.set_bit(Self::BURN_BIT, value);
+    }
+
+    pub fn set_frozen(&mut self, value: bool) {
+        self.set_bit(Self::FREEZE_BIT, value);
+    }
+
+    pub fn set_paralyzed(&mut self, value: bool) {
+        self.set_bit(Self::PARALYSIS_BIT, value);
+    }
+
+    const fn bit(self, bit: u32) -> bool {
+        self.0 & (1 << bit) != 0
+    }
+
+    fn set_bit(&mut self, bit: u32, value: bool) {
+        if value {
+            self.0 |= 1 << bit;
+        } else {
+            self.0 &= !(1 << bit);
+        }
+    }
+}
+
+/// A Pokemon with its substructures decrypted and stored in the growth, attacks, EVs/condition and
+/// miscellaneous order, regardless of its personality value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecryptedPokemon {
+    data: [u8; PokemonData::SIZE],
+}
+
+impl DecryptedPokemon {
+    pub const SUBSTRUCTURE_SIZE: usize = 12;
+    pub const GROWTH_OFFSET: usize = PokemonData::SUBSTRUCTURES_OFFSET;
+    pub const ATTACKS_OFFSET: usize = Self::GROWTH_OFFSET + Self::SUBSTRUCTURE_SIZE;
+    pub const EVS_OFFSET: usize = Self::ATTACKS_OFFSET + Self::SUBSTRUCTURE_SIZE;
+    pub const MISC_OFFSET: usize = Self::EVS_OFFSET + Self::SUBSTRUCTURE_SIZE;
+
+    pub const SPECIES_OFFSET: usize = Self::GROWTH_OFFSET;
+    pub const HELD_ITEM_OFFSET: usize = Self::GROWTH_OFFSET + 2;
+    pub const EXPERIENCE_OFFSET: usize = Self::GROWTH_OFFSET + 4;
+    pub const PP_UPS_OFFSET: usize = Self::GROWTH_OFFSET + 8;
+    pub const MOVES_OFFSET: usize = Self::ATTACKS_OFFSET;
+    pub const PP_OFFSET: usize = Self::ATTACKS_OFFSET + 8;
+    pub const IVS_OFFSET: usize = Self::MISC_OFFSET + 4;
+
+    pub const MOVE_COUNT: usize = 4;
+    pub const STAT_COUNT: usize = 6;
+    pub const IS_EGG_BIT: u8 = 30;
+    /// Last bit of the origins info, stored in the upper half of the first misc word.
+    pub const OT_GENDER_BIT: u8 = 31;
+
+    /// Decrypts and unshuffles the substructures of a raw Pokemon.
+    pub fn decrypt(raw: &[u8; PokemonData::SIZE]) -> Self {
+        let mut data = *raw;
+        let raw = Data::<PokemonData>::new(raw);
+        let substructures: &mut [u8; PokemonData::SUBSTRUCTURES_SIZE] = (&mut data
+            [PokemonData::SUBSTRUCTURES_OFFSET..])
+            .try_into()
+            .unwrap();
+        decrypt_data(substructures, Self::key(raw.personality(), raw.ot_id()));
+
+        Self {
+            data: decrypt_substructures(&data),
+        }
+    }
+
+    /// Shuffles and encrypts the substructures back into the raw format, updating the checksum.
+    pub fn encrypt(&self) -> [u8; PokemonData::SIZE] {
+        let mut raw = encrypt_substructures(&self.to_bytes(), self.personality());
+        let substructures: &mut [u8; PokemonData::SUBSTRUCTURES_SIZE] = (&mut raw
+            [PokemonData::SUBSTRUCTURES_OFFSET..])
+            .try_into()
+            .unwrap();
+        encrypt_data(substructures, Self::key(self.personality(), self.ot_id()));
+        raw
+    }
+
+    /// Returns the Pokemon in the 80 byte `.pkm` format, which is the encrypted layout stored in
+    /// the PC, see [`DecryptedPokemon::encrypt`].
+    pub fn to_pkm_bytes(&self) -> [u8; PokemonData::SIZE] {
+        self.encrypt()
+    }
+
+    /// Decrypts a Pokemon in the `.pkm` format, see [`DecryptedPokemon::to_pkm_bytes`], failing if
+    /// the checksum doesn't match.
+    pub fn from_pkm_bytes(data: &[u8; PokemonData::SIZE]) -> PkResult<Self> {
+        let pokemon = Self::decrypt(data);
+        if !pokemon.is_checksum_valid() {
+            return Err(PkError::InvalidData("pkm checksum"));
+        }
+        Ok(pokemon)
+    }
+
+    /// Creates a Pokemon from bytes whose substructures are already decrypted and unshuffled, as
+    /// returned by [`DecryptedPokemon::to_bytes`].
+    pub fn from_bytes(data: [u8; PokemonData::SIZE]) -> Self {
+        Self { data }
+    }
+
+    /// Returns the decrypted bytes, with the substructures unshuffled and the checksum updated.
+    pub fn to_bytes(&self) -> [u8; PokemonData::SIZE] {
+        let mut data = self.data;
+        mem::write_half_word(
+            &mut data,
+            PokemonData::CHECKSUM_OFFSET,
+            self.calculate_checksum(),
+        );
+        data
+    }
+
+    fn calculate_checksum(&self) -> u16 {
+        let mut substructures = [0; PokemonData::SUBSTRUCTURES_SIZE];
+        substructures.copy_from_slice(&self.data[PokemonData::SUBSTRUCTURES_OFFSET..]);
+        checksum(&substructures)
+    }
+
+    fn key(personality: u32, ot_id: TrainerId) -> u32 {
+        personality ^ (u32::from(ot_id.private) << 16 | u32::from(ot_id.public))
+    }
+
+    fn as_data(&self) -> Data<'_, PokemonData> {
+        Data::new(&self.data)
+    }
+
+    pub fn personality(&self) -> u32 {
+        self.as_data().personality()
+    }
+
+    pub fn ot_id(&self) -> TrainerId {
+        self.as_data().ot_id()
+    }
+
+    /// Returns whether the Pokemon is shiny, see [`is_shiny`].
+    pub fn is_shiny(&self) -> bool {
+        self.as_data().is_shiny()
+    }
+
+    pub fn has_species(&self) -> bool {
+        self.as_data().has_species()
+    }
+
+    /// Returns whether the stored checksum matches the decrypted substructures.
+    pub fn is_checksum_valid(&self) -> bool {
+        self.calculate_checksum() == mem::read_half_word(&self.data, PokemonData::CHECKSUM_OFFSET)
+    }
+
+    pub fn set_ot_id(&mut self, ot_id: TrainerId) {
+        mem::write_half_word(
+            &mut self.data,
+            PokemonData::PUBLIC_OT_ID_OFFSET,
+            ot_id.public,
+        );
+        mem::write_half_word(
+            &mut self.data,
+            PokemonData::PRIVATE_OT_ID_OFFSET,
+            ot_id.private,
+        );
+    }
+
+    pub fn ot_name_raw(&self) -> [u8; PokemonData::OT_NAME_LENGTH] {
+        std::array::from_fn(|index| self.data[PokemonData::OT_NAME_OFFSET + index])
+    }
+
+    pub fn set_ot_name_raw(&mut self, name: [u8; PokemonData::OT_NAME_LENGTH]) {
+        mem::copy_bytes(&mut self.data, PokemonData::OT_NAME_OFFSET, &name);
+    }
+
+    pub fn ot_gender(&self) -> Gender {
+        match mem::read_bits(&self.data, Self::MISC_OFFSET, Self::OT_GENDER_BIT, 1) {
+            0 => Gender::Male,
+            _ => Gender::Female,
+        }
+    }
+
+    /// Sets the gender of the original trainer, which can't be genderless.
+    pub fn set_ot_gender(&mut self, gender: Gender) {
+        debug_assert_ne!(gender, Gender::Genderless, "trainers can't be genderless");
+        let female = u32::from(gender == Gender::Female);
+        mem::write_bits(
+            &mut self.data,
+            Self::MISC_OFFSET,
+            Self::OT_GENDER_BIT,
+            1,
+            female,
+        );
+    }
+
+    pub fn species(&self) -> u16 {
+        mem::read_half_word(&self.data, Self::SPECIES_OFFSET)
+    }
+
+    pub fn set_species(&mut self, species: u16) {
+        mem::write_half_word(&mut self.data, Self::SPECIES_OFFSET, species);
+    }
+
+    pub fn held_item(&self) -> u16 {
+        mem::read_half_word(&self.data, Self::HELD_ITEM_OFFSET)
+    }
+
+    pub fn set_held_item(&mut self, item_id: u16) {
+        mem::write_half_word(&mut self.data, Self::HELD_ITEM_OFFSET, item_id);
+    }
+
+    pub fn experience(&self) -> u32 {
+        mem::read_word(&self.data, Self::EXPERIENCE_OFFSET)
+    }
+
+    pub fn set_experience(&mut self, experience: u32) {
+        mem::write_word(&mut self.data, Self::EXPERIENCE_OFFSET, experience);
+    }
+
+    pub fn is_egg(&self) -> bool {
+        mem::read_bits(&self.data, Self::IVS_OFFSET, Self::IS_EGG_BIT, 1) != 0
+    }
+
+    /// Returns the move IDs, 0 being an empty move slot.
+    pub fn moves(&self) -> [u16; Self::MOVE_COUNT] {
+        std::array::from_fn(|index| mem::read_half_word(&self.data, Self::MOVES_OFFSET + index * 2))
+    }
+
+    pub fn set_moves(&mut self, moves: [u16; Self::MOVE_COUNT]) {
+        for (index, move_id) in moves.into_iter().enumerate() {
+            mem::write_half_word(&mut self.data, Self::MOVES_OFFSET + index * 2, move_id);
+        }
+    }
+
+    /// Sets the move in `slot` (0 to 3), 0 empties the slot. Only the range of the ID is checked,
+    /// not whether the species can learn the move.
+    pub fn set_move_id(&mut self, slot: usize, id: u16) -> PkResult<()> {
+        check_index("move slot", slot, Self::MOVE_COUNT)?;
+        if !moves::is_valid(id) {
+            return Err(PkError::InvalidData("move id"));
+        }
+        mem::write_half_word(&mut self.data, Self::MOVES_OFFSET + slot * 2, id);
+        Ok(())
+    }
+
+    /// Returns the effort values in the order HP, attack, defense, speed, special attack and
+    /// special defense.
+    pub fn evs(&self) -> [u8; Self::STAT_COUNT] {
+        std::array::from_fn(|index| self.data[Self::EVS_OFFSET + index])
+    }
+
+    /// Sets the effort values, see [`DecryptedPokemon::evs`] for the order.
+    pub fn set_evs(&mut self, evs: [u8; Self::STAT_COUNT]) {
+        mem::copy_bytes(&mut self.data, Self::EVS_OFFSET, &evs);
+    }
+
+    pub fn pp(&self) -> [u8; Self::MOVE_COUNT] {
+        std::array::from_fn(|index| self.data[Self::PP_OFFSET + index])
+    }
+
+    pub fn set_pp(&mut self, pp: [u8; Self::MOVE_COUNT]) {
+        mem::copy_bytes(&mut self.data, Self::PP_OFFSET, &pp);
+    }
+
+    /// Sets the effort values to the spread, nothing is written if the spread isn't valid.
+    pub fn apply_ev_spread(&mut self, spread: EvSpread) -> PkResult<()> {
+        if !spread.is_valid() {
+            return Err(PkError::InvalidData("ev spread"));
+        }
+        self.set_evs(spread.evs());
+        Ok(())
+    }
+
+    /// Sets the individual values to the set, nothing is written if the set isn't valid.
+    pub fn apply_iv_set(&mut self, ivs: IvSet) -> PkResult<()> {
+        if !ivs.is_valid() {
+            return Err(PkError::InvalidData("iv set"));
+        }
+        self.set_ivs(ivs.ivs());
+        Ok(())
+    }
+
+    /// Returns how many PP Ups (0 to 3) have been used on every move.
+    pub fn pp_ups(&self) -> [u8; Self::MOVE_COUNT] {
+        let pp_ups = self.data[Self::PP_UPS_OFFSET];
+        std::array::from_fn(|index| (pp_ups >> (index * 2)) & 0b11)
+    }
+
+    /// Returns the individual values in the same order as [`DecryptedPokemon::evs`].
+    pub fn ivs(&self) -> [u8; Self::STAT_COUNT] {
+        std::array::from_fn(|index| {
+            mem::read_bits(&self.data, Self::IVS_OFFSET, index as u8 * 5, 5) as u8
+        })
+    }
+
+    pub fn set_ivs(&mut self, ivs: [u8; Self::STAT_COUNT]) {
+        for (index, iv) in ivs.into_iter().enumerate() {
+            let bit_offset = index as u8 * 5;
+            mem::write_bits(
+                &mut self.data,
+                Self::IVS_OFFSET,
+                bit_offset,
+                5,
+                u32::from(iv),
+            );
+        }
+    }
+
+    /// Returns the nature, which is determined by the personality value.
+    pub fn nature(&self) -> Nature {
+        Nature::from_personality(self.personality())
+    }
+
+    /// Returns the level the experience corresponds to for the given growth rate.
+    pub fn level(&self, growth_rate: GrowthRate) -> u8 {
+        let experience = self.experience();
+        (2..=100)
+            .take_while(|&level| growth_rate.experience(level) <= experience)
+            .last()
+            .unwrap_or(1)
+    }
+
+    /// Calculates the stats of the Pokemon from its base stats, level, IVs, EVs and nature, in the
+    /// same order as [`DecryptedPokemon::evs`].
+    pub fn calculate_stats(&self, base_stats: &BaseStats) -> [u16; Self::STAT_COUNT] {
+        let level = self.level(base_stats.growth_rate);
+        StatKind::ALL.map(|stat| self.calculated_stat(stat, base_stats.get(stat), level))
+    }
+
+    /// Calculates a single stat at `level` from the base stat of the species and the IVs, EVs and
+    /// nature of the Pokemon.
+    pub fn calculated_stat(&self, stat: StatKind, base: u8, level: u8) -> u16 {
+        let level = u32::from(level);
+        let value = (2 * u32::from(base)
+            + u32::from(self.ivs()[stat as usize])
+            + u32::from(self.evs()[stat as usize]) / 4)
+            * level
+            / 100;
+        if stat == StatKind::Hp {
+            return if base == 1 {
+                // Shedinja always has 1 HP.
+                1
+            } else {
+                (value + level + 10) as u16
+            };
+        }
+
+        let nature = self.nature();
+        let value = value + 5;
+        let value = if nature.raised_stat() == Some(stat) {
+            value * 110 / 100
+        } else if nature.lowered_stat() == Some(stat) {
+            value * 90 / 100
+        } else {
+            value
+        };
+        value as u16
+    }
+
+    /// Returns the encrypted bytes as stored in the PC, see [`DecryptedPokemon::encrypt`].
+    pub fn to_box_bytes(&self) -> [u8; PokemonData::SIZE] {
+        self.encrypt()
+    }
+
+    /// Returns the encrypted bytes as stored in the party, with freshly calculated stats, full HP,
+    /// no status condition and no mail. `base_stats` returns the base stats of a species.
+    pub fn to_party_bytes(
+        &self,
+        base_stats: impl Fn(u16) -> BaseStats,
+    ) -> [u8; PartyPokemonData::SIZE] {
+        let base_stats = base_stats(self.species());
+        let stats = self.calculate_stats(&base_stats);
+
+        let mut bytes = [0; PartyPokemonData::SIZE];
+        bytes[..PokemonData::SIZE].copy_from_slice(&self.encrypt());
+        bytes[PartyPokemonData::LEVEL_OFFSET] = self.level(base_stats.growth_rate);
+        bytes[PartyPokemonData::MAIL_OFFSET] = PartyPokemonData::NO_MAIL;
+        mem::write_half_word(&mut bytes, PartyPokemonData::CURRENT_HP_OFFSET, stats[0]);
+        mem::write_half_word(&mut bytes, PartyPokemonData::MAX_HP_OFFSET, stats[0]);
+        for (index, stat) in stats[1..].iter().enumerate() {
+            mem::write_half_word(
+                &mut bytes,
+                PartyPokemonData::STATS_OFFSET + index * 2,
+                *stat,
+            );
+        }
+        bytes
+    }
+
+    /// Returns the PP of every move once fully restored, taking PP Ups into account.
+    pub fn max_pp(&self) -> PkResult<[u8; Self::MOVE_COUNT]> {
+        let moves = self.moves();
+        let pp_ups = self.pp_ups();
+        let mut max_pp = [0; Self::MOVE_COUNT];
+        for (index, max_pp) in max_pp.iter_mut().enumerate() {
+            *max_pp = moves::max_pp(moves[index], pp_ups[index])
+                .ok_or(PkError::InvalidData("move id"))?;
+        }
+        Ok(max_pp)
+    }
+}
+
+/// The base stats of a species, needed to calculate the stats of its Pokemon.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BaseStats {
+    pub hp: u8,
+    pub attack: u8,
+    pub defense: u8,
+    pub speed: u8,
+    pub special_attack: u8,
+    pub special_defense: u8,
+    pub growth_rate: GrowthRate,
+}
+
+impl BaseStats {
+    /// Returns the base stat for `stat`.
+    pub fn get(&self, stat: StatKind) -> u8 {
+        match stat {
+            StatKind::Hp => self.hp,
+            StatKind::Attack => self.attack,
+            StatKind::Defense => self.defense,
+            StatKind::Speed => self.speed,
+            StatKind::SpecialAttack => self.special_attack,
+            StatKind::SpecialDefense => self.special_defense,
+        }
+    }
+}
+
+/// How much experience a species needs to level up.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GrowthRate {
+    #[default]
+    MediumFast,
+    Erratic,
+    Fluctuating,
+    MediumSlow,
+    Fast,
+    Slow,
+}
+
+impl GrowthRate {
+    /// Returns the total experience needed to reach the level.
+    pub fn experience(self, level: u8) -> u32 {
+        if level <= 1 {
+            return 0;
+        }
+        let n = i64::from(level.min(100));
+        let cube = n * n * n;
+        let experience = match self {
+            GrowthRate::MediumFast => cube,
+            GrowthRate::Erratic => match n {
+                ..=49 => cube * (100 - n) / 50,
+                50..=67 => cube * (150 - n) / 100,
+                68..=97 => cube * ((1911 - 10 * n) / 3) / 500,
+                _ => cube * (160 - n) / 100,
+            },
+            GrowthRate::Fluctuating => match n {
+                ..=14 => cube * ((n + 1) / 3 + 24) / 50,
+                15..=35 => cube * (n + 14) / 50,
+                _ => cube * (n / 2 + 32) / 50,
+            },
+            GrowthRate::MediumSlow => 6 * cube / 5 - 15 * n * n + 100 * n - 140,
+            GrowthRate::Fast => 4 * cube / 5,
+            GrowthRate::Slow => 5 * cube / 4,
+        };
+        experience as u32
+    }
+}
+
+/// An effort value distribution, with constants for the common competitive ones. The presets
+/// put 4 points in a third stat, since only every 4 effort values raise a stat by one point at
+/// level 100.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EvSpread {
+    pub hp: u8,
+    pub attack: u8,
+    pub defense: u8,
+    pub sp_atk: u8,
+    pub sp_def: u8,
+    pub speed: u8,
+}
+
+impl EvSpread {
+    /// The most effort values a Pokemon can have across all stats.
+    pub const MAX_TOTAL: u16 = 510;
+
+    /// 85 in every stat.
+    pub const BALANCED: Self = Self::new(85, 85, 85, 85, 85, 85);
+    /// 252 attack, 252 speed and 4 HP.
+    pub const PHYSICAL_SWEEPER: Self = Self::new(4, 252, 0, 0, 0, 252);
+    /// 252 special attack, 252 speed and 4 HP.
+    pub const SPECIAL_SWEEPER: Self = Self::new(4, 0, 0, 252, 0, 252);
+    /// 252 HP, 252 defense and 4 special defense.
+    pub const PHYSICAL_TANK: Self = Self::new(252, 0, 252, 0, 4, 0);
+    /// 252 HP, 252 special defense and 4 defense.
+    pub const SPECIAL_TANK: Self = Self::new(252, 0, 4, 0, 252, 0);
+
+    const fn new(hp: u8, attack: u8, defense: u8, sp_atk: u8, sp_def: u8, speed: u8) -> Self {
+        Self {
+            hp,
+            attack,
+            defense,
+            sp_atk,
+            sp_def,
+            speed,
+        }
+    }
+
+    /// Returns the effort values, in the order used by [`DecryptedPokemon::evs`].
+    pub const fn evs(self) -> [u8; DecryptedPokemon::STAT_COUNT] {
+        [
+            self.hp,
+            self.attack,
+            self.defense,
+            self.speed,
+            self.sp_atk,
+            self.sp_def,
+        ]
+    }
+
+    pub fn total(self) -> u16 {
+        self.evs().into_iter().map(u16::from).sum()
+    }
+
+    /// Returns whether the spread doesn't exceed [`EvSpread::MAX_TOTAL`]. Every stat fits in a
+    /// byte, so they can't exceed the per-stat maximum of 255.
+    pub fn is_valid(self) -> bool {
+        self.total() <= Self::MAX_TOTAL
+    }
+}
+
+impl Default for EvSpread {
+    fn default() -> Self {
+        Self::BALANCED
+    }
+}
+
+/// A set of individual values, with constants for the common competitive ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IvSet {
+    pub hp: u8,
+    pub attack: u8,
+    pub defense: u8,
+    pub sp_atk: u8,
+    pub sp_def: u8,
+    pub speed: u8,
+}
+
+impl IvSet {
+    /// The highest individual value of a stat.
+    pub const MAX_IV: u8 = 31;
+
+    /// 31 in every stat.
+    pub const ALL_31: Self = Self::new(31, 31, 31, 31, 31, 31);
+    /// 0 speed to move first under Trick Room, 31 in every other stat.
+    pub const TRICK_ROOM_SLOW: Self = Self::new(31, 31, 31, 31, 31, 0);
+    /// 0 attack to minimize confusion damage, 31 in every other stat.
+    pub const SPECIAL_ATTACKER_NO_ATTACK: Self = Self::new(31, 0, 31, 31, 31, 31);
+
+    const fn new(hp: u8, attack: u8, defense: u8, sp_atk: u8, sp_def: u8, speed: u8) -> Self {
+        Self {
+            hp,
+            attack,
+            defense,
+            sp_atk,
+            sp_def,
+            speed,
+        }
+    }
+
+    /// Returns the individual values, in the order used by [`DecryptedPokemon::ivs`].
+    pub const fn ivs(self) -> [u8; DecryptedPokemon::STAT_COUNT] {
+        [
+            self.hp,
+            self.attack,
+            self.defense,
+            self.speed,
+            self.sp_atk,
+            self.sp_def,
+        ]
+    }
+
+    /// Returns whether no stat exceeds [`IvSet::MAX_IV`].
+    pub fn is_valid(self) -> bool {
+        self.ivs().into_iter().all(|iv| iv <= Self::MAX_IV)
+    }
+}
+
+/// Reasons a Pokemon can't be migrated to Gen 4 through the Pal Park.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MigrationWarning {
+    Egg,
+    /// The stored checksum doesn't match, the Pokemon would show up as a Bad Egg.
+    InvalidChecksum,
+    InvalidSpecies(u16),
+    InvalidMove(u16),
+    /// Pokemon knowing HMs must forget them first.
+    KnowsHm(u16),
+    /// Pokemon holding mail must have it taken first.
+    HoldsMail(u16),
+}
+
+/// Checks whether the Pokemon could be migrated through the Pal Park, returning every issue found.
+pub fn check_pal_park_eligibility(pokemon: &DecryptedPokemon) -> Vec<MigrationWarning> {
+    if !pokemon.is_checksum_valid() {
+        return vec![MigrationWarning::InvalidChecksum];
+    }
+
+    let mut warnings = vec![];
+    if pokemon.is_egg() {
+        warnings.push(MigrationWarning::Egg);
+    } else if !species::is_valid(pokemon.species()) {
+        warnings.push(MigrationWarning::InvalidSpecies(pokemon.species()));
+    }
+    for move_id in pokemon.moves() {
+        if moves::name(move_id).is_none() {
+            warnings.push(MigrationWarning::InvalidMove(move_id));
+        } else if moves::HMS.contains(&move_id) {
+            warnings.push(MigrationWarning::KnowsHm(move_id));
+        }
+    }
+    if ItemId::try_from(pokemon.held_item()).is_ok_and(ItemId::is_mail) {
+        warnings.push(MigrationWarning::HoldsMail(pokemon.held_item()));
+    }
+    warnings
+}
+
+/// Where a Pokemon is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PokemonLocation {
+    /// Party slot.
+    Party(usize),
+    /// Box index and slot within the box.
+    Box(usize, usize),
+}
+
+/// Position of the growth (0), attacks (1), EVs/condition (2) and misc (3) substructures in
+/// the encrypted data, indexed by the personality value modulo 24.
+pub const SUBSTRUCTURE_ORDERS: [[usize; 4]; 24] = [
+    [0, 1, 2, 3],
+    [0, 1, 3, 2],
+    [0, 2, 1, 3],
+    [0, 3, 1, 2],
+    [0, 2, 3, 1],
+    [0, 3, 2, 1],
+    [1, 0, 2, 3],
+    [1, 0, 3, 2],
+    [2, 0, 1, 3],
+    [3, 0, 1, 2],
+    [2, 0, 3, 1],
+    [3, 0, 2, 1],
+    [1, 2, 0, 3],
+    [1, 3, 0, 2],
+    [2, 1, 0, 3],
+    [3, 1, 0, 2],
+    [2, 3, 0, 1],
+    [3, 2, 0, 1],
+    [1, 2, 3, 0],
+    [1, 3, 2, 0],
+    [2, 1, 3, 0],
+    [3, 1, 2, 0],
+    [2, 3, 1, 0],
+    [3, 2, 1, 0],
+];
+
+/// Unshuffles the substructures of a Pokemon into the growth, attacks, EVs/condition and misc
+/// order, using the personality value stored in `data`. The substructures must already be
+/// decrypted, see [`decrypt_data`].
+pub fn decrypt_substructures(data: &[u8; PokemonData::SIZE]) -> [u8; PokemonData::SIZE] {
+    let personality = Data::<PokemonData>::new(data).personality();
+    let mut unshuffled = *data;
+    for (substructure, position) in substructure_order(personality).into_iter().enumerate() {
+        let to = substructure_offset(substructure);
+        let from = substructure_offset(position);
+        unshuffled[to..(to + DecryptedPokemon::SUBSTRUCTURE_SIZE)]
+            .copy_from_slice(&data[from..(from + DecryptedPokemon::SUBSTRUCTURE_SIZE)]);
+    }
+    unshuffled
+}
+
+/// Shuffles the substructures of a Pokemon into the order given by `personality`, the inverse of
+/// [`decrypt_substructures`]. The substructures are not encrypted, see [`encrypt_data`].
+pub fn encrypt_substructures(
+    data: &[u8; PokemonData::SIZE],
+    personality: u32,
+) -> [u8; PokemonData::SIZE] {
+    let mut shuffled = *data;
+    for (substructure, position) in substructure_order(personality).into_iter().enumerate() {
+        let from = substructure_offset(substructure);
+        let to = substructure_offset(position);
+        shuffled[to..(to + DecryptedPokemon::SUBSTRUCTURE_SIZE)]
+            .copy_from_slice(&data[from..(from + DecryptedPokemon::SUBSTRUCTURE_SIZE)]);
+    }
+    shuffled
+}
+
+fn substructure_order(personality: u32) -> [usize; 4] {
+    SUBSTRUCTURE_ORDERS[(personality % 24) as usize]
+}
+
+fn substructure_offset(index: usize) -> usize {
+    PokemonData::SUBSTRUCTURES_OFFSET + index * DecryptedPokemon::SUBSTRUCTURE_SIZE
+}
+
+/// Decrypts the substructures of a Pokemon in place. The key is the personality value XORed with
+/// the original trainer ID.
+pub fn decrypt_data(data: &mut [u8; PokemonData::SUBSTRUCTURES_SIZE], key: u32) {
+    for offset in (0..PokemonData::SUBSTRUCTURES_SIZE).step_by(4) {
+        let word = mem::read_word(data, offset);
+        mem::write_word(data, offset, word ^ key);
+    }
+}
+
+/// Encrypts the substructures of a Pokemon in place, see [`decrypt_data`].
+pub fn encrypt_data(data: &mut [u8; PokemonData::SUBSTRUCTURES_SIZE], key: u32) {
+    decrypt_data(data, key);
+}
+
+/// Returns whether a Pokemon with the given personality value, caught by the trainer with
+/// `ot_id`, is shiny. That's the case when XORing both halves of the trainer ID and of the
+/// personality value gives a number below 8.
+pub fn is_shiny(personality: u32, ot_id: TrainerId) -> bool {
+    let value =
+        ot_id.public ^ ot_id.private ^ (personality >> 16) as u16 ^ (personality & 0xFFFF) as u16;
+    value < 8
+}
+
+/// Calculates the checksum of the decrypted substructures, which is the sum of every half word.
+pub fn checksum(data: &[u8; PokemonData::SUBSTRUCTURES_SIZE]) -> u16 {
+    (0..PokemonData::SUBSTRUCTURES_SIZE)
+        .step_by(2)
+        .fold(0u16, |checksum, offset| {
+            checksum.wrapping_add(mem::read_half_word(data, offset))
+        })
+}
@@ -0,0 +1,1742 @@
+//! Species data, indexed by the species ID used internally by the games.
+//!
+//! The IDs of Kanto and Johto species match their National Pokedex number, they are followed by
+//! 25 unused IDs and then the Hoenn species, in the order of the Hoenn Pokedex.
+
+use crate::common::Type;
+
+use super::pokemon::{BaseStats, GrowthRate};
+
+/// Name of every species, species 0 is used for empty slots.
+const NAMES: [&str; COUNT] = [
+    "---",
+    "Bulbasaur",
+    "Ivysaur",
+    "Venusaur",
+    "Charmander",
+    "Charmeleon",
+    "Charizard",
+    "Squirtle",
+    "Wartortle",
+    "Blastoise",
+    "Caterpie",
+    "Metapod",
+    "Butterfree",
+    "Weedle",
+    "Kakuna",
+    "Beedrill",
+    "Pidgey",
+    "Pidgeotto",
+    "Pidgeot",
+    "Rattata",
+    "Raticate",
+    "Spearow",
+    "Fearow",
+    "Ekans",
+    "Arbok",
+    "Pikachu",
+    "Raichu",
+    "Sandshrew",
+    "Sandslash",
+    "Nidoran♀",
+    "Nidorina",
+    "Nidoqueen",
+    "Nidoran♂",
+    "Nidorino",
+    "Nidoking",
+    "Clefairy",
+    "Clefable",
+    "Vulpix",
+    "Ninetales",
+    "Jigglypuff",
+    "Wigglytuff",
+    "Zubat",
+    "Golbat",
+    "Oddish",
+    "Gloom",
+    "Vileplume",
+    "Paras",
+    "Parasect",
+    "Venonat",
+    "Venomoth",
+    "Diglett",
+    "Dugtrio",
+    "Meowth",
+    "Persian",
+    "Psyduck",
+    "Golduck",
+    "Mankey",
+    "Primeape",
+    "Growlithe",
+    "Arcanine",
+    "Poliwag",
+    "Poliwhirl",
+    "Poliwrath",
+    "Abra",
+    "Kadabra",
+    "Alakazam",
+    "Machop",
+    "Machoke",
+    "Machamp",
+    "Bellsprout",
+    "Weepinbell",
+    "Victreebel",
+    "Tentacool",
+    "Tentacruel",
+    "Geodude",
+    "Graveler",
+    "Golem",
+    "Ponyta",
+    "Rapidash",
+    "Slowpoke",
+    "Slowbro",
+    "Magnemite",
+    "Magneton",
+    "Farfetch'd",
+    "Doduo",
+    "Dodrio",
+    "Seel",
+    "Dewgong",
+    "Grimer",
+    "Muk",
+    "Shellder",
+    "Cloyster",
+    "Gastly",
+    "Haunter",
+    "Gengar",
+    "Onix",
+    "Drowzee",
+    "Hypno",
+    "Krabby",
+    "Kingler",
+    "Voltorb",
+    "Electrode",
+    "Exeggcute",
+    "Exeggutor",
+    "Cubone",
+    "Marowak",
+    "Hitmonlee",
+    "Hitmonchan",
+    "Lickitung",
+    "Koffing",
+    "Weezing",
+    "Rhyhorn",
+    "Rhydon",
+    "Chansey",
+    "Tangela",
+    "Kangaskhan",
+    "Horsea",
+    "Seadra",
+    "Goldeen",
+    "Seaking",
+    "Staryu",
+    "Starmie",
+    "Mr. Mime",
+    "Scyther",
+    "Jynx",
+    "Electabuzz",
+    "Magmar",
+    "Pinsir",
+    "Tauros",
+    "Magikarp",
+    "Gyarados",
+    "Lapras",
+    "Ditto",
+    "Eevee",
+    "Vaporeon",
+    "Jolteon",
+    "Flareon",
+    "Porygon",
+    "Omanyte",
+    "Omastar",
+    "Kabuto",
+    "Kabutops",
+    "Aerodactyl",
+    "Snorlax",
+    "Articuno",
+    "Zapdos",
+    "Moltres",
+    "Dratini",
+    "Dragonair",
+    "Dragonite",
+    "Mewtwo",
+    "Mew",
+    "Chikorita",
+    "Bayleef",
+    "Meganium",
+    "Cyndaquil",
+    "Quilava",
+    "Typhlosion",
+    "Totodile",
+    "Croconaw",
+    "Feraligatr",
+    "Sentret",
+    "Furret",
+    "Hoothoot",
+    "Noctowl",
+    "Ledyba",
+    "Ledian",
+    "Spinarak",
+    "Ariados",
+    "Crobat",
+    "Chinchou",
+    "Lanturn",
+    "Pichu",
+    "Cleffa",
+    "Igglybuff",
+    "Togepi",
+    "Togetic",
+    "Natu",
+    "Xatu",
+    "Mareep",
+    "Flaaffy",
+    "Ampharos",
+    "Bellossom",
+    "Marill",
+    "Azumarill",
+    "Sudowoodo",
+    "Politoed",
+    "Hoppip",
+    "Skiploom",
+    "Jumpluff",
+    "Aipom",
+    "Sunkern",
+    "Sunflora",
+    "Yanma",
+    "Wooper",
+    "Quagsire",
+    "Espeon",
+    "Umbreon",
+    "Murkrow",
+    "Slowking",
+    "Misdreavus",
+    "Unown",
+    "Wobbuffet",
+    "Girafarig",
+    "Pineco",
+    "Forretress",
+    "Dunsparce",
+    "Gligar",
+    "Steelix",
+    "Snubbull",
+    "Granbull",
+    "Qwilfish",
+    "Scizor",
+    "Shuckle",
+    "Heracross",
+    "Sneasel",
+    "Teddiursa",
+    "Ursaring",
+    "Slugma",
+    "Magcargo",
+    "Swinub",
+    "Piloswine",
+    "Corsola",
+    "Remoraid",
+    "Octillery",
+    "Delibird",
+    "Mantine",
+    "Skarmory",
+    "Houndour",
+    "Houndoom",
+    "Kingdra",
+    "Phanpy",
+    "Donphan",
+    "Porygon2",
+    "Stantler",
+    "Smeargle",
+    "Tyrogue",
+    "Hitmontop",
+    "Smoochum",
+    "Elekid",
+    "Magby",
+    "Miltank",
+    "Blissey",
+    "Raikou",
+    "Entei",
+    "Suicune",
+    "Larvitar",
+    "Pupitar",
+    "Tyranitar",
+    "Lugia",
+    "Ho-Oh",
+    "Celebi",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "?",
+    "Treecko",
+    "Grovyle",
+    "Sceptile",
+    "Torchic",
+    "Combusken",
+    "Blaziken",
+    "Mudkip",
+    "Marshtomp",
+    "Swampert",
+    "Poochyena",
+    "Mightyena",
+    "Zigzagoon",
+    "Linoone",
+    "Wurmple",
+    "Silcoon",
+    "Beautifly",
+    "Cascoon",
+    "Dustox",
+    "Lotad",
+    "Lombre",
+    "Ludicolo",
+    "Seedot",
+    "Nuzleaf",
+    "Shiftry",
+    "Nincada",
+    "Ninjask",
+    "Shedinja",
+    "Taillow",
+    "Swellow",
+    "Shroomish",
+    "Breloom",
+    "Spinda",
+    "Wingull",
+    "Pelipper",
+    "Surskit",
+    "Masquerain",
+    "Wailmer",
+    "Wailord",
+    "Skitty",
+    "Delcatty",
+    "Kecleon",
+    "Baltoy",
+    "Claydol",
+    "Nosepass",
+    "Torkoal",
+    "Sableye",
+    "Barboach",
+    "Whiscash",
+    "Luvdisc",
+    "Corphish",
+    "Crawdaunt",
+    "Feebas",
+    "Milotic",
+    "Carvanha",
+    "Sharpedo",
+    "Trapinch",
+    "Vibrava",
+    "Flygon",
+    "Makuhita",
+    "Hariyama",
+    "Electrike",
+    "Manectric",
+    "Numel",
+    "Camerupt",
+    "Spheal",
+    "Sealeo",
+    "Walrein",
+    "Cacnea",
+    "Cacturne",
+    "Snorunt",
+    "Glalie",
+    "Lunatone",
+    "Solrock",
+    "Azurill",
+    "Spoink",
+    "Grumpig",
+    "Plusle",
+    "Minun",
+    "Mawile",
+    "Meditite",
+    "Medicham",
+    "Swablu",
+    "Altaria",
+    "Wynaut",
+    "Duskull",
+    "Dusclops",
+    "Roselia",
+    "Slakoth",
+    "Vigoroth",
+    "Slaking",
+    "Gulpin",
+    "Swalot",
+    "Tropius",
+    "Whismur",
+    "Loudred",
+    "Exploud",
+    "Clamperl",
+    "Huntail",
+    "Gorebyss",
+    "Absol",
+    "Shuppet",
+    "Banette",
+    "Seviper",
+    "Zangoose",
+    "Relicanth",
+    "Aron",
+    "Lairon",
+    "Aggron",
+    "Castform",
+    "Volbeat",
+    "Illumise",
+    "Lileep",
+    "Cradily",
+    "Anorith",
+    "Armaldo",
+    "Ralts",
+    "Kirlia",
+    "Gardevoir",
+    "Bagon",
+    "Shelgon",
+    "Salamence",
+    "Beldum",
+    "Metang",
+    "Metagross",
+    "Regirock",
+    "Regice",
+    "Registeel",
+    "Kyogre",
+    "Groudon",
+    "Rayquaza",
+    "Latias",
+    "Latios",
+    "Jirachi",
+    "Deoxys",
+    "Chimecho",
+    "Egg",
+];
+
+/// Number of species IDs, including the empty species 0 and the egg.
+pub const COUNT: usize = 413;
+
+pub const EGG: u16 = 412;
+
+/// IDs between the Johto and Hoenn species, which aren't used by any Pokemon.
+pub const UNUSED: core::ops::RangeInclusive<u16> = 252..=276;
+
+/// Returns whether the ID belongs to an actual species, i.e. it isn't empty, unused or an egg.
+pub fn is_valid(species_id: u16) -> bool {
+    species_id != 0 && species_id < EGG && !UNUSED.contains(&species_id)
+}
+
+/// Returns the name of the species, if the ID is valid.
+pub fn name(species_id: u16) -> Option<&'static str> {
+    NAMES.get(species_id as usize).copied()
+}
+
+/// A species, named after its National Pokedex entry.
+///
+/// Converting from an ID uses the internal species ID stored in the save file, see the module
+/// documentation. IDs which don't belong to a species are kept as [`Species::Unknown`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Species {
+    Bulbasaur,
+    Ivysaur,
+    Venusaur,
+    Charmander,
+    Charmeleon,
+    Charizard,
+    Squirtle,
+    Wartortle,
+    Blastoise,
+    Caterpie,
+    Metapod,
+    Butterfree,
+    Weedle,
+    Kakuna,
+    Beedrill,
+    Pidgey,
+    Pidgeotto,
+    Pidgeot,
+    Rattata,
+    Raticate,
+    Spearow,
+    Fearow,
+    Ekans,
+    Arbok,
+    Pikachu,
+    Raichu,
+    Sandshrew,
+    Sandslash,
+    NidoranF,
+    Nidorina,
+    Nidoqueen,
+    NidoranM,
+    Nidorino,
+    Nidoking,
+    Clefairy,
+    Clefable,
+    Vulpix,
+    Ninetales,
+    Jigglypuff,
+    Wigglytuff,
+    Zubat,
+    Golbat,
+    Oddish,
+    Gloom,
+    Vileplume,
+    Paras,
+    Parasect,
+    Venonat,
+    Venomoth,
+    Diglett,
+    Dugtrio,
+    Meowth,
+    Persian,
+    Psyduck,
+    Golduck,
+    Mankey,
+    Primeape,
+    Growlithe,
+    Arcanine,
+    Poliwag,
+    Poliwhirl,
+    Poliwrath,
+    Abra,
+    Kadabra,
+    Alakazam,
+    Machop,
+    Machoke,
+    Machamp,
+    Bellsprout,
+    Weepinbell,
+    Victreebel,
+    Tentacool,
+    Tentacruel,
+    Geodude,
+    Graveler,
+    Golem,
+    Ponyta,
+    Rapidash,
+    Slowpoke,
+    Slowbro,
+    Magnemite,
+    Magneton,
+    Farfetchd,
+    Doduo,
+    Dodrio,
+    Seel,
+    Dewgong,
+    Grimer,
+    Muk,
+    Shellder,
+    Cloyster,
+    Gastly,
+    Haunter,
+    Gengar,
+    Onix,
+    Drowzee,
+    Hypno,
+    Krabby,
+    Kingler,
+    Voltorb,
+    Electrode,
+    Exeggcute,
+    Exeggutor,
+    Cubone,
+    Marowak,
+    Hitmonlee,
+    Hitmonchan,
+    Lickitung,
+    Koffing,
+    Weezing,
+    Rhyhorn,
+    Rhydon,
+    Chansey,
+    Tangela,
+    Kangaskhan,
+    Horsea,
+    Seadra,
+    Goldeen,
+    Seaking,
+    Staryu,
+    Starmie,
+    MrMime,
+    Scyther,
+    Jynx,
+    Electabuzz,
+    Magmar,
+    Pinsir,
+    Tauros,
+    Magikarp,
+    Gyarados,
+    Lapras,
+    Ditto,
+    Eevee,
+    Vaporeon,
+    Jolteon,
+    Flareon,
+    Porygon,
+    Omanyte,
+    Omastar,
+    Kabuto,
+    Kabutops,
+    Aerodactyl,
+    Snorlax,
+    Articuno,
+    Zapdos,
+    Moltres,
+    Dratini,
+    Dragonair,
+    Dragonite,
+    Mewtwo,
+    Mew,
+    Chikorita,
+    Bayleef,
+    Meganium,
+    Cyndaquil,
+    Quilava,
+    Typhlosion,
+    Totodile,
+    Croconaw,
+    Feraligatr,
+    Sentret,
+    Furret,
+    Hoothoot,
+    Noctowl,
+    Ledyba,
+    Ledian,
+    Spinarak,
+    Ariados,
+    Crobat,
+    Chinchou,
+    Lanturn,
+    Pichu,
+    Cleffa,
+    Igglybuff,
+    Togepi,
+    Togetic,
+    Natu,
+    Xatu,
+    Mareep,
+    Flaaffy,
+    Ampharos,
+    Bellossom,
+    Marill,
+    Azumarill,
+    Sudowoodo,
+    Politoed,
+    Hoppip,
+    Skiploom,
+    Jumpluff,
+    Aipom,
+    Sunkern,
+    Sunflora,
+    Yanma,
+    Wooper,
+    Quagsire,
+    Espeon,
+    Umbreon,
+    Murkrow,
+    Slowking,
+    Misdreavus,
+    Unown,
+    Wobbuffet,
+    Girafarig,
+    Pineco,
+    Forretress,
+    Dunsparce,
+    Gligar,
+    Steelix,
+    Snubbull,
+    Granbull,
+    Qwilfish,
+    Scizor,
+    Shuckle,
+    Heracross,
+    Sneasel,
+    Teddiursa,
+    Ursaring,
+    Slugma,
+    Magcargo,
+    Swinub,
+    Piloswine,
+    Corsola,
+    Remoraid,
+    Octillery,
+    Delibird,
+    Mantine,
+    Skarmory,
+    Houndour,
+    Houndoom,
+    Kingdra,
+    Phanpy,
+    Donphan,
+    Porygon2,
+    Stantler,
+    Smeargle,
+    Tyrogue,
+    Hitmontop,
+    Smoochum,
+    Elekid,
+    Magby,
+    Miltank,
+    Blissey,
+    Raikou,
+    Entei,
+    Suicune,
+    Larvitar,
+    Pupitar,
+    Tyranitar,
+    Lugia,
+    HoOh,
+    Celebi,
+    Treecko,
+    Grovyle,
+    Sceptile,
+    Torchic,
+    Combusken,
+    Blaziken,
+    Mudkip,
+    Marshtomp,
+    Swampert,
+    Poochyena,
+    Mightyena,
+    Zigzagoon,
+    Linoone,
+    Wurmple,
+    Silcoon,
+    Beautifly,
+    Cascoon,
+    Dustox,
+    Lotad,
+    Lombre,
+    Ludicolo,
+    Seedot,
+    Nuzleaf,
+    Shiftry,
+    Taillow,
+    Swellow,
+    Wingull,
+    Pelipper,
+    Ralts,
+    Kirlia,
+    Gardevoir,
+    Surskit,
+    Masquerain,
+    Shroomish,
+    Breloom,
+    Slakoth,
+    Vigoroth,
+    Slaking,
+    Nincada,
+    Ninjask,
+    Shedinja,
+    Whismur,
+    Loudred,
+    Exploud,
+    Makuhita,
+    Hariyama,
+    Azurill,
+    Nosepass,
+    Skitty,
+    Delcatty,
+    Sableye,
+    Mawile,
+    Aron,
+    Lairon,
+    Aggron,
+    Meditite,
+    Medicham,
+    Electrike,
+    Manectric,
+    Plusle,
+    Minun,
+    Volbeat,
+    Illumise,
+    Roselia,
+    Gulpin,
+    Swalot,
+    Carvanha,
+    Sharpedo,
+    Wailmer,
+    Wailord,
+    Numel,
+    Camerupt,
+    Torkoal,
+    Spoink,
+    Grumpig,
+    Spinda,
+    Trapinch,
+    Vibrava,
+    Flygon,
+    Cacnea,
+    Cacturne,
+    Swablu,
+    Altaria,
+    Zangoose,
+    Seviper,
+    Lunatone,
+    Solrock,
+    Barboach,
+    Whiscash,
+    Corphish,
+    Crawdaunt,
+    Baltoy,
+    Claydol,
+    Lileep,
+    Cradily,
+    Anorith,
+    Armaldo,
+    Feebas,
+    Milotic,
+    Castform,
+    Kecleon,
+    Shuppet,
+    Banette,
+    Duskull,
+    Dusclops,
+    Tropius,
+    Chimecho,
+    Absol,
+    Wynaut,
+    Snorunt,
+    Glalie,
+    Spheal,
+    Sealeo,
+    Walrein,
+    Clamperl,
+    Huntail,
+    Gorebyss,
+    Relicanth,
+    Luvdisc,
+    Bagon,
+    Shelgon,
+    Salamence,
+    Beldum,
+    Metang,
+    Metagross,
+    Regirock,
+    Regice,
+    Registeel,
+    Latias,
+    Latios,
+    Kyogre,
+    Groudon,
+    Rayquaza,
+    Jirachi,
+    Deoxys,
+    /// An empty, unused or egg ID.
+    Unknown(u16),
+}
+
+impl Species {
+    /// Every species, in National Pokedex order.
+    pub const ALL: [Self; 386] = [
+        Self::Bulbasaur,
+        Self::Ivysaur,
+        Self::Venusaur,
+        Self::Charmander,
+        Self::Charmeleon,
+        Self::Charizard,
+        Self::Squirtle,
+        Self::Wartortle,
+        Self::Blastoise,
+        Self::Caterpie,
+        Self::Metapod,
+        Self::Butterfree,
+        Self::Weedle,
+        Self::Kakuna,
+        Self::Beedrill,
+        Self::Pidgey,
+        Self::Pidgeotto,
+        Self::Pidgeot,
+        Self::Rattata,
+        Self::Raticate,
+        Self::Spearow,
+        Self::Fearow,
+        Self::Ekans,
+        Self::Arbok,
+        Self::Pikachu,
+        Self::Raichu,
+        Self::Sandshrew,
+        Self::Sandslash,
+        Self::NidoranF,
+        Self::Nidorina,
+        Self::Nidoqueen,
+        Self::NidoranM,
+        Self::Nidorino,
+        Self::Nidoking,
+        Self::Clefairy,
+        Self::Clefable,
+        Self::Vulpix,
+        Self::Ninetales,
+        Self::Jigglypuff,
+        Self::Wigglytuff,
+        Self::Zubat,
+        Self::Golbat,
+        Self::Oddish,
+        Self::Gloom,
+        Self::Vileplume,
+        Self::Paras,
+        Self::Parasect,
+        Self::Venonat,
+        Self::Venomoth,
+        Self::Diglett,
+        Self::Dugtrio,
+        Self::Meowth,
+        Self::Persian,
+        Self::Psyduck,
+        Self::Golduck,
+        Self::Mankey,
+        Self::Primeape,
+        Self::Growlithe,
+        Self::Arcanine,
+        Self::Poliwag,
+        Self::Poliwhirl,
+        Self::Poliwrath,
+        Self::Abra,
+        Self::Kadabra,
+        Self::Alakazam,
+        Self::Machop,
+        Self::Machoke,
+        Self::Machamp,
+        Self::Bellsprout,
+        Self::Weepinbell,
+        Self::Victreebel,
+        Self::Tentacool,
+        Self::Tentacruel,
+        Self::Geodude,
+        Self::Graveler,
+        Self::Golem,
+        Self::Ponyta,
+        Self::Rapidash,
+        Self::Slowpoke,
+        Self::Slowbro,
+        Self::Magnemite,
+        Self::Magneton,
+        Self::Farfetchd,
+        Self::Doduo,
+        Self::Dodrio,
+        Self::Seel,
+        Self::Dewgong,
+        Self::Grimer,
+        Self::Muk,
+        Self::Shellder,
+        Self::Cloyster,
+        Self::Gastly,
+        Self::Haunter,
+        Self::Gengar,
+        Self::Onix,
+        Self::Drowzee,
+        Self::Hypno,
+        Self::Krabby,
+        Self::Kingler,
+        Self::Voltorb,
+        Self::Electrode,
+        Self::Exeggcute,
+        Self::Exeggutor,
+        Self::Cubone,
+        Self::Marowak,
+        Self::Hitmonlee,
+        Self::Hitmonchan,
+        Self::Lickitung,
+        Self::Koffing,
+        Self::Weezing,
+        Self::Rhyhorn,
+        Self::Rhydon,
+        Self::Chansey,
+        Self::Tangela,
+        Self::Kangaskhan,
+        Self::Horsea,
+        Self::Seadra,
+        Self::Goldeen,
+        Self::Seaking,
+        Self::Staryu,
+        Self::Starmie,
+        Self::MrMime,
+        Self::Scyther,
+        Self::Jynx,
+        Self::Electabuzz,
+        Self::Magmar,
+        Self::Pinsir,
+        Self::Tauros,
+        Self::Magikarp,
+        Self::Gyarados,
+        Self::Lapras,
+        Self::Ditto,
+        Self::Eevee,
+        Self::Vaporeon,
+        Self::Jolteon,
+        Self::Flareon,
+        Self::Porygon,
+        Self::Omanyte,
+        Self::Omastar,
+        Self::Kabuto,
+        Self::Kabutops,
+        Self::Aerodactyl,
+        Self::Snorlax,
+        Self::Articuno,
+        Self::Zapdos,
+        Self::Moltres,
+        Self::Dratini,
+        Self::Dragonair,
+        Self::Dragonite,
+        Self::Mewtwo,
+        Self::Mew,
+        Self::Chikorita,
+        Self::Bayleef,
+        Self::Meganium,
+        Self::Cyndaquil,
+        Self::Quilava,
+        Self::Typhlosion,
+        Self::Totodile,
+        Self::Croconaw,
+        Self::Feraligatr,
+        Self::Sentret,
+        Self::Furret,
+        Self::Hoothoot,
+        Self::Noctowl,
+        Self::Ledyba,
+        Self::Ledian,
+        Self::Spinarak,
+        Self::Ariados,
+        Self::Crobat,
+        Self::Chinchou,
+        Self::Lanturn,
+        Self::Pichu,
+        Self::Cleffa,
+        Self::Igglybuff,
+        Self::Togepi,
+        Self::Togetic,
+        Self::Natu,
+        Self::Xatu,
+        Self::Mareep,
+        Self::Flaaffy,
+        Self::Ampharos,
+        Self::Bellossom,
+        Self::Marill,
+        Self::Azumarill,
+        Self::Sudowoodo,
+        Self::Politoed,
+        Self::Hoppip,
+        Self::Skiploom,
+        Self::Jumpluff,
+        Self::Aipom,
+        Self::Sunkern,
+        Self::Sunflora,
+        Self::Yanma,
+        Self::Wooper,
+        Self::Quagsire,
+        Self::Espeon,
+        Self::Umbreon,
+        Self::Murkrow,
+        Self::Slowking,
+        Self::Misdreavus,
+        Self::Unown,
+        Self::Wobbuffet,
+        Self::Girafarig,
+        Self::Pineco,
+        Self::Forretress,
+        Self::Dunsparce,
+        Self::Gligar,
+        Self::Steelix,
+        Self::Snubbull,
+        Self::Granbull,
+        Self::Qwilfish,
+        Self::Scizor,
+        Self::Shuckle,
+        Self::Heracross,
+        Self::Sneasel,
+        Self::Teddiursa,
+        Self::Ursaring,
+        Self::Slugma,
+        Self::Magcargo,
+        Self::Swinub,
+        Self::Piloswine,
+        Self::Corsola,
+        Self::Remoraid,
+        Self::Octillery,
+        Self::Delibird,
+        Self::Mantine,
+        Self::Skarmory,
+        Self::Houndour,
+        Self::Houndoom,
+        Self::Kingdra,
+        Self::Phanpy,
+        Self::Donphan,
+        Self::Porygon2,
+        Self::Stantler,
+        Self::Smeargle,
+        Self::Tyrogue,
+        Self::Hitmontop,
+        Self::Smoochum,
+        Self::Elekid,
+        Self::Magby,
+        Self::Miltank,
+        Self::Blissey,
+        Self::Raikou,
+        Self::Entei,
+        Self::Suicune,
+        Self::Larvitar,
+        Self::Pupitar,
+        Self::Tyranitar,
+        Self::Lugia,
+        Self::HoOh,
+        Self::Celebi,
+        Self::Treecko,
+        Self::Grovyle,
+        Self::Sceptile,
+        Self::Torchic,
+        Self::Combusken,
+        Self::Blaziken,
+        Self::Mudkip,
+        Self::Marshtomp,
+        Self::Swampert,
+        Self::Poochyena,
+        Self::Mightyena,
+        Self::Zigzagoon,
+        Self::Linoone,
+        Self::Wurmple,
+        Self::Silcoon,
+        Self::Beautifly,
+        Self::Cascoon,
+        Self::Dustox,
+        Self::Lotad,
+        Self::Lombre,
+        Self::Ludicolo,
+        Self::Seedot,
+        Self::Nuzleaf,
+        Self::Shiftry,
+        Self::Taillow,
+        Self::Swellow,
+        Self::Wingull,
+        Self::Pelipper,
+        Self::Ralts,
+        Self::Kirlia,
+        Self::Gardevoir,
+        Self::Surskit,
+        Self::Masquerain,
+        Self::Shroomish,
+        Self::Breloom,
+        Self::Slakoth,
+        Self::Vigoroth,
+        Self::Slaking,
+        Self::Nincada,
+        Self::Ninjask,
+        Self::Shedinja,
+        Self::Whismur,
+        Self::Loudred,
+        Self::Exploud,
+        Self::Makuhita,
+        Self::Hariyama,
+        Self::Azurill,
+        Self::Nosepass,
+        Self::Skitty,
+        Self::Delcatty,
+        Self::Sableye,
+        Self::Mawile,
+        Self::Aron,
+        Self::Lairon,
+        Self::Aggron,
+        Self::Meditite,
+        Self::Medicham,
+        Self::Electrike,
+        Self::Manectric,
+        Self::Plusle,
+        Self::Minun,
+        Self::Volbeat,
+        Self::Illumise,
+        Self::Roselia,
+        Self::Gulpin,
+        Self::Swalot,
+        Self::Carvanha,
+        Self::Sharpedo,
+        Self::Wailmer,
+        Self::Wailord,
+        Self::Numel,
+        Self::Camerupt,
+        Self::Torkoal,
+        Self::Spoink,
+        Self::Grumpig,
+        Self::Spinda,
+        Self::Trapinch,
+        Self::Vibrava,
+        Self::Flygon,
+        Self::Cacnea,
+        Self::Cacturne,
+        Self::Swablu,
+        Self::Altaria,
+        Self::Zangoose,
+        Self::Seviper,
+        Self::Lunatone,
+        Self::Solrock,
+        Self::Barboach,
+        Self::Whiscash,
+        Self::Corphish,
+        Self::Crawdaunt,
+        Self::Baltoy,
+        Self::Claydol,
+        Self::Lileep,
+        Self::Cradily,
+        Self::Anorith,
+        Self::Armaldo,
+        Self::Feebas,
+        Self::Milotic,
+        Self::Castform,
+        Self::Kecleon,
+        Self::Shuppet,
+        Self::Banette,
+        Self::Duskull,
+        Self::Dusclops,
+        Self::Tropius,
+        Self::Chimecho,
+        Self::Absol,
+        Self::Wynaut,
+        Self::Snorunt,
+        Self::Glalie,
+        Self::Spheal,
+        Self::Sealeo,
+        Self::Walrein,
+        Self::Clamperl,
+        Self::Huntail,
+        Self::Gorebyss,
+        Self::Relicanth,
+        Self::Luvdisc,
+        Self::Bagon,
+        Self::Shelgon,
+        Self::Salamence,
+        Self::Beldum,
+        Self::Metang,
+        Self::Metagross,
+        Self::Regirock,
+        Self::Regice,
+        Self::Registeel,
+        Self::Latias,
+        Self::Latios,
+        Self::Kyogre,
+        Self::Groudon,
+        Self::Rayquaza,
+        Self::Jirachi,
+        Self::Deoxys,
+    ];
+
+    /// Returns the National Pokedex number, or `None` for [`Species::Unknown`].
+    pub fn national_dex(self) -> Option<u16> {
+        Self::ALL
+            .iter()
+            .position(|&species| species == self)
+            .map(|index| index as u16 + 1)
+    }
+
+    /// Returns the name, or `None` for [`Species::Unknown`].
+    pub fn name(self) -> Option<&'static str> {
+        match self {
+            Self::Unknown(_) => None,
+            species => name(u16::from(species)),
+        }
+    }
+
+    /// Returns the base stats, or `None` for [`Species::Unknown`].
+    pub fn base_stats(self) -> Option<BaseStats> {
+        self.national_dex().map(|number| {
+            let ([hp, attack, defense, speed, special_attack, special_defense], growth_rate, ..) =
+                SPECIES_DATA[number as usize - 1];
+            BaseStats {
+                hp,
+                attack,
+                defense,
+                speed,
+                special_attack,
+                special_defense,
+                growth_rate,
+            }
+        })
+    }
+
+    /// Returns the primary and, if any, secondary type, or `None` for [`Species::Unknown`].
+    pub fn types(self) -> Option<(Type, Option<Type>)> {
+        self.national_dex().map(|number| {
+            let (.., primary, secondary) = SPECIES_DATA[number as usize - 1];
+            (primary, secondary)
+        })
+    }
+}
+
+impl From<u16> for Species {
+    fn from(species_id: u16) -> Self {
+        if !is_valid(species_id) {
+            Self::Unknown(species_id)
+        } else if species_id <= LAST_JOHTO_ID {
+            Self::ALL[species_id as usize - 1]
+        } else {
+            let national_dex = HOENN_NATIONAL_DEX[(species_id - FIRST_HOENN_ID) as usize];
+            Self::ALL[national_dex as usize - 1]
+        }
+    }
+}
+
+impl From<Species> for u16 {
+    fn from(species: Species) -> Self {
+        let Some(national_dex) = species.national_dex() else {
+            let Species::Unknown(species_id) = species else {
+                unreachable!("only unknown species lack a national dex number");
+            };
+            return species_id;
+        };
+        if national_dex <= LAST_JOHTO_ID {
+            national_dex
+        } else {
+            let index = HOENN_NATIONAL_DEX
+                .iter()
+                .position(|&number| number == national_dex)
+                .expect("every Hoenn species has an ID");
+            FIRST_HOENN_ID + index as u16
+        }
+    }
+}
+
+const LAST_JOHTO_ID: u16 = *UNUSED.start() - 1;
+const FIRST_HOENN_ID: u16 = *UNUSED.end() + 1;
+
+/// National Pokedex number of the Hoenn species, indexed by their ID minus [`FIRST_HOENN_ID`].
+const HOENN_NATIONAL_DEX: [u16; 135] = [
+    252, 253, 254, 255, 256, 257, 258, 259, 260, 261, 262, 263, 264, 265, 266, 267, 268, 269, 270,
+    271, 272, 273, 274, 275, 290, 291, 292, 276, 277, 285, 286, 327, 278, 279, 283, 284, 320, 321,
+    300, 301, 352, 343, 344, 299, 324, 302, 339, 340, 370, 341, 342, 349, 350, 318, 319, 328, 329,
+    330, 296, 297, 309, 310, 322, 323, 363, 364, 365, 331, 332, 361, 362, 337, 338, 298, 325, 326,
+    311, 312, 303, 307, 308, 333, 334, 360, 355, 356, 315, 287, 288, 289, 316, 317, 357, 293, 294,
+    295, 366, 367, 368, 359, 353, 354, 336, 335, 369, 304, 305, 306, 351, 313, 314, 345, 346, 347,
+    348, 280, 281, 282, 371, 372, 373, 374, 375, 376, 377, 378, 379, 382, 383, 384, 380, 381, 385,
+    386, 358,
+];
+
+/// Base stats, growth rate and types of every species, in National Pokedex order. The stats are
+/// in the order of [`crate::common::StatKind`].
+const SPECIES_DATA: [([u8; 6], GrowthRate, Type, Option<Type>); 386] = {
+    use GrowthRate::*;
+    use Type::*;
+
+    [
+        ([45, 49, 49, 45, 65, 65], MediumSlow, Grass, Some(Poison)),
+        ([60, 62, 63, 60, 80, 80], MediumSlow, Grass, Some(Poison)),
+        ([80, 82, 83, 80, 100, 100], MediumSlow, Grass, Some(Poison)),
+        ([39, 52, 43, 65, 60, 50], MediumSlow, Fire, None),
+        ([58, 64, 58, 80, 80, 65], MediumSlow, Fire, None),
+        ([78, 84, 78, 100, 109, 85], MediumSlow, Fire, Some(Flying)),
+        ([44, 48, 65, 43, 50, 64], MediumSlow, Water, None),
+        ([59, 63, 80, 58, 65, 80], MediumSlow, Water, None),
+        ([79, 83, 100, 78, 85, 105], MediumSlow, Water, None),
+        ([45, 30, 35, 45, 20, 20], MediumFast, Bug, None),
+        ([50, 20, 55, 30, 25, 25], MediumFast, Bug, None),
+        ([60, 45, 50, 70, 80, 80], MediumFast, Bug, Some(Flying)),
+        ([40, 35, 30, 50, 20, 20], MediumFast, Bug, Some(Poison)),
+        ([45, 25, 50, 35, 25, 25], MediumFast, Bug, Some(Poison)),
+        ([65, 80, 40, 75, 45, 80], MediumFast, Bug, Some(Poison)),
+        ([40, 45, 40, 56, 35, 35], MediumSlow, Normal, Some(Flying)),
+        ([63, 60, 55, 71, 50, 50], MediumSlow, Normal, Some(Flying)),
+        ([83, 80, 75, 91, 70, 70], MediumSlow, Normal, Some(Flying)),
+        ([30, 56, 35, 72, 25, 35], MediumFast, Normal, None),
+        ([55, 81, 60, 97, 50, 70], MediumFast, Normal, None),
+        ([40, 60, 30, 70, 31, 31], MediumFast, Normal, Some(Flying)),
+        ([65, 90, 65, 100, 61, 61], MediumFast, Normal, Some(Flying)),
+        ([35, 60, 44, 55, 40, 54], MediumFast, Poison, None),
+        ([60, 85, 69, 80, 65, 79], MediumFast, Poison, None),
+        ([35, 55, 30, 90, 50, 40], MediumFast, Electric, None),
+        ([60, 90, 55, 100, 90, 80], MediumFast, Electric, None),
+        ([50, 75, 85, 40, 20, 30], MediumFast, Ground, None),
+        ([75, 100, 110, 65, 45, 55], MediumFast, Ground, None),
+        ([55, 47, 52, 41, 40, 40], MediumSlow, Poison, None),
+        ([70, 62, 67, 56, 55, 55], MediumSlow, Poison, None),
+        ([90, 82, 87, 76, 75, 85], MediumSlow, Poison, Some(Ground)),
+        ([46, 57, 40, 50, 40, 40], MediumSlow, Poison, None),
+        ([61, 72, 57, 65, 55, 55], MediumSlow, Poison, None),
+        ([81, 92, 77, 85, 85, 75], MediumSlow, Poison, Some(Ground)),
+        ([70, 45, 48, 35, 60, 65], Fast, Normal, None),
+        ([95, 70, 73, 60, 85, 90], Fast, Normal, None),
+        ([38, 41, 40, 65, 50, 65], MediumFast, Fire, None),
+        ([73, 76, 75, 100, 81, 100], MediumFast, Fire, None),
+        ([115, 45, 20, 20, 45, 25], Fast, Normal, None),
+        ([140, 70, 45, 45, 75, 50], Fast, Normal, None),
+        ([40, 45, 35, 55, 30, 40], MediumFast, Poison, Some(Flying)),
+        ([75, 80, 70, 90, 65, 75], MediumFast, Poison, Some(Flying)),
+        ([45, 50, 55, 30, 75, 65], MediumSlow, Grass, Some(Poison)),
+        ([60, 65, 70, 40, 85, 75], MediumSlow, Grass, Some(Poison)),
+        ([75, 80, 85, 50, 100, 90], MediumSlow, Grass, Some(Poison)),
+        ([35, 70, 55, 25, 45, 55], MediumFast, Bug, Some(Grass)),
+        ([60, 95, 80, 30, 60, 80], MediumFast, Bug, Some(Grass)),
+        ([60, 55, 50, 45, 40, 55], MediumFast, Bug, Some(Poison)),
+        ([70, 65, 60, 90, 90, 75], MediumFast, Bug, Some(Poison)),
+        ([10, 55, 25, 95, 35, 45], MediumFast, Ground, None),
+        ([35, 80, 50, 120, 50, 70], MediumFast, Ground, None),
+        ([40, 45, 35, 90, 40, 40], MediumFast, Normal, None),
+        ([65, 70, 60, 115, 65, 65], MediumFast, Normal, None),
+        ([50, 52, 48, 55, 65, 50], MediumFast, Water, None),
+        ([80, 82, 78, 85, 95, 80], MediumFast, Water, None),
+        ([40, 80, 35, 70, 35, 45], MediumFast, Fighting, None),
+        ([65, 105, 60, 95, 60, 70], MediumFast, Fighting, None),
+        ([55, 70, 45, 60, 70, 50], Slow, Fire, None),
+        ([90, 110, 80, 95, 100, 80], Slow, Fire, None),
+        ([40, 50, 40, 90, 40, 40], MediumSlow, Water, None),
+        ([65, 65, 65, 90, 50, 50], MediumSlow, Water, None),
+        ([90, 85, 95, 70, 70, 90], MediumSlow, Water, Some(Fighting)),
+        ([25, 20, 15, 90, 105, 55], MediumSlow, Psychic, None),
+        ([40, 35, 30, 105, 120, 70], MediumSlow, Psychic, None),
+        ([55, 50, 45, 120, 135, 85], MediumSlow, Psychic, None),
+        ([70, 80, 50, 35, 35, 35], MediumSlow, Fighting, None),
+        ([80, 100, 70, 45, 50, 60], MediumSlow, Fighting, None),
+        ([90, 130, 80, 55, 65, 85], MediumSlow, Fighting, None),
+        ([50, 75, 35, 40, 70, 30], MediumSlow, Grass, Some(Poison)),
+        ([65, 90, 50, 55, 85, 45], MediumSlow, Grass, Some(Poison)),
+        ([80, 105, 65, 70, 100, 60], MediumSlow, Grass, Some(Poison)),
+        ([40, 40, 35, 70, 50, 100], Slow, Water, Some(Poison)),
+        ([80, 70, 65, 100, 80, 120], Slow, Water, Some(Poison)),
+        ([40, 80, 100, 20, 30, 30], MediumSlow, Rock, Some(Ground)),
+        ([55, 95, 115, 35, 45, 45], MediumSlow, Rock, Some(Ground)),
+        ([80, 110, 130, 45, 55, 65], MediumSlow, Rock, Some(Ground)),
+        ([50, 85, 55, 90, 65, 65], MediumFast, Fire, None),
+        ([65, 100, 70, 105, 80, 80], MediumFast, Fire, None),
+        ([90, 65, 65, 15, 40, 40], MediumFast, Water, Some(Psychic)),
+        ([95, 75, 110, 30, 100, 80], MediumFast, Water, Some(Psychic)),
+        ([25, 35, 70, 45, 95, 55], MediumFast, Electric, Some(Steel)),
+        ([50, 60, 95, 70, 120, 70], MediumFast, Electric, Some(Steel)),
+        ([52, 65, 55, 60, 58, 62], MediumFast, Normal, Some(Flying)),
+        ([35, 85, 45, 75, 35, 35], MediumFast, Normal, Some(Flying)),
+        ([60, 110, 70, 100, 60, 60], MediumFast, Normal, Some(Flying)),
+        ([65, 45, 55, 45, 45, 70], MediumFast, Water, None),
+        ([90, 70, 80, 70, 70, 95], MediumFast, Water, Some(Ice)),
+        ([80, 80, 50, 25, 40, 50], MediumFast, Poison, None),
+        ([105, 105, 75, 50, 65, 100], MediumFast, Poison, None),
+        ([30, 65, 100, 40, 45, 25], Slow, Water, None),
+        ([50, 95, 180, 70, 85, 45], Slow, Water, Some(Ice)),
+        ([30, 35, 30, 80, 100, 35], MediumSlow, Ghost, Some(Poison)),
+        ([45, 50, 45, 95, 115, 55], MediumSlow, Ghost, Some(Poison)),
+        ([60, 65, 60, 110, 130, 75], MediumSlow, Ghost, Some(Poison)),
+        ([35, 45, 160, 70, 30, 45], MediumFast, Rock, Some(Ground)),
+        ([60, 48, 45, 42, 43, 90], MediumFast, Psychic, None),
+        ([85, 73, 70, 67, 73, 115], MediumFast, Psychic, None),
+        ([30, 105, 90, 50, 25, 25], MediumFast, Water, None),
+        ([55, 130, 115, 75, 50, 50], MediumFast, Water, None),
+        ([40, 30, 50, 100, 55, 55], MediumFast, Electric, None),
+        ([60, 50, 70, 140, 80, 80], MediumFast, Electric, None),
+        ([60, 40, 80, 40, 60, 45], Slow, Grass, Some(Psychic)),
+        ([95, 95, 85, 55, 125, 65], Slow, Grass, Some(Psychic)),
+        ([50, 50, 95, 35, 40, 50], MediumFast, Ground, None),
+        ([60, 80, 110, 45, 50, 80], MediumFast, Ground, None),
+        ([50, 120, 53, 87, 35, 110], MediumFast, Fighting, None),
+        ([50, 105, 79, 76, 35, 110], MediumFast, Fighting, None),
+        ([90, 55, 75, 30, 60, 75], MediumFast, Normal, None),
+        ([40, 65, 95, 35, 60, 45], MediumFast, Poison, None),
+        ([65, 90, 120, 60, 85, 70], MediumFast, Poison, None),
+        ([80, 85, 95, 25, 30, 30], Slow, Ground, Some(Rock)),
+        ([105, 130, 120, 40, 45, 45], Slow, Ground, Some(Rock)),
+        ([250, 5, 5, 50, 35, 105], Fast, Normal, None),
+        ([65, 55, 115, 60, 100, 40], MediumFast, Grass, None),
+        ([105, 95, 80, 90, 40, 80], MediumFast, Normal, None),
+        ([30, 40, 70, 60, 70, 25], MediumFast, Water, None),
+        ([55, 65, 95, 85, 95, 45], MediumFast, Water, None),
+        ([45, 67, 60, 63, 35, 50], MediumFast, Water, None),
+        ([80, 92, 65, 68, 65, 80], MediumFast, Water, None),
+        ([30, 45, 55, 85, 70, 55], Slow, Water, None),
+        ([60, 75, 85, 115, 100, 85], Slow, Water, Some(Psychic)),
+        ([40, 45, 65, 90, 100, 120], MediumFast, Psychic, None),
+        ([70, 110, 80, 105, 55, 80], MediumFast, Bug, Some(Flying)),
+        ([65, 50, 35, 95, 115, 95], MediumFast, Ice, Some(Psychic)),
+        ([65, 83, 57, 105, 95, 85], MediumFast, Electric, None),
+        ([65, 95, 57, 93, 100, 85], MediumFast, Fire, None),
+        ([65, 125, 100, 85, 55, 70], Slow, Bug, None),
+        ([75, 100, 95, 110, 40, 70], Slow, Normal, None),
+        ([20, 10, 55, 80, 15, 20], Slow, Water, None),
+        ([95, 125, 79, 81, 60, 100], Slow, Water, Some(Flying)),
+        ([130, 85, 80, 60, 85, 95], Slow, Water, Some(Ice)),
+        ([48, 48, 48, 48, 48, 48], MediumFast, Normal, None),
+        ([55, 55, 50, 55, 45, 65], MediumFast, Normal, None),
+        ([130, 65, 60, 65, 110, 95], MediumFast, Water, None),
+        ([65, 65, 60, 130, 110, 95], MediumFast, Electric, None),
+        ([65, 130, 60, 65, 95, 110], MediumFast, Fire, None),
+        ([65, 60, 70, 40, 85, 75], MediumFast, Normal, None),
+        ([35, 40, 100, 35, 90, 55], MediumFast, Rock, Some(Water)),
+        ([70, 60, 125, 55, 115, 70], MediumFast, Rock, Some(Water)),
+        ([30, 80, 90, 55, 55, 45], MediumFast, Rock, Some(Water)),
+        ([60, 115, 105, 80, 65, 70], MediumFast, Rock, Some(Water)),
+        ([80, 105, 65, 130, 60, 75], Slow, Rock, Some(Flying)),
+        ([160, 110, 65, 30, 65, 110], Slow, Normal, None),
+        ([90, 85, 100, 85, 95, 125], Slow, Ice, Some(Flying)),
+        ([90, 90, 85, 100, 125, 90], Slow, Electric, Some(Flying)),
+        ([90, 100, 90, 90, 125, 85], Slow, Fire, Some(Flying)),
+        ([41, 64, 45, 50, 50, 50], Slow, Dragon, None),
+        ([61, 84, 65, 70, 70, 70], Slow, Dragon, None),
+        ([91, 134, 95, 80, 100, 100], Slow, Dragon, Some(Flying)),
+        ([106, 110, 90, 130, 154, 90], Slow, Psychic, None),
+        ([100, 100, 100, 100, 100, 100], MediumSlow, Psychic, None),
+        ([45, 49, 65, 45, 49, 65], MediumSlow, Grass, None),
+        ([60, 62, 80, 60, 63, 80], MediumSlow, Grass, None),
+        ([80, 82, 100, 80, 83, 100], MediumSlow, Grass, None),
+        ([39, 52, 43, 65, 60, 50], MediumSlow, Fire, None),
+        ([58, 64, 58, 80, 80, 65], MediumSlow, Fire, None),
+        ([78, 84, 78, 100, 109, 85], MediumSlow, Fire, None),
+        ([50, 65, 64, 43, 44, 48], MediumSlow, Water, None),
+        ([65, 80, 80, 58, 59, 63], MediumSlow, Water, None),
+        ([85, 105, 100, 78, 79, 83], MediumSlow, Water, None),
+        ([35, 46, 34, 20, 35, 45], MediumFast, Normal, None),
+        ([85, 76, 64, 90, 45, 55], MediumFast, Normal, None),
+        ([60, 30, 30, 50, 36, 56], MediumFast, Normal, Some(Flying)),
+        ([100, 50, 50, 70, 76, 96], MediumFast, Normal, Some(Flying)),
+        ([40, 20, 30, 55, 40, 80], Fast, Bug, Some(Flying)),
+        ([55, 35, 50, 85, 55, 110], Fast, Bug, Some(Flying)),
+        ([40, 60, 40, 30, 40, 40], Fast, Bug, Some(Poison)),
+        ([70, 90, 70, 40, 60, 60], Fast, Bug, Some(Poison)),
+        ([85, 90, 80, 130, 70, 80], MediumFast, Poison, Some(Flying)),
+        ([75, 38, 38, 67, 56, 56], Slow, Water, Some(Electric)),
+        ([125, 58, 58, 67, 76, 76], Slow, Water, Some(Electric)),
+        ([20, 40, 15, 60, 35, 35], MediumFast, Electric, None),
+        ([50, 25, 28, 15, 45, 55], Fast, Normal, None),
+        ([90, 30, 15, 15, 40, 20], Fast, Normal, None),
+        ([35, 20, 65, 20, 40, 65], Fast, Normal, None),
+        ([55, 40, 85, 40, 80, 105], Fast, Normal, Some(Flying)),
+        ([40, 50, 45, 70, 70, 45], MediumFast, Psychic, Some(Flying)),
+        ([65, 75, 70, 95, 95, 70], MediumFast, Psychic, Some(Flying)),
+        ([55, 40, 40, 35, 65, 45], MediumSlow, Electric, None),
+        ([70, 55, 55, 45, 80, 60], MediumSlow, Electric, None),
+        ([90, 75, 75, 55, 115, 90], MediumSlow, Electric, None),
+        ([75, 80, 85, 50, 90, 100], MediumSlow, Grass, None),
+        ([70, 20, 50, 40, 20, 50], Fast, Water, None),
+        ([100, 50, 80, 50, 50, 80], Fast, Water, None),
+        ([70, 100, 115, 30, 30, 65], MediumFast, Rock, None),
+        ([90, 75, 75, 70, 90, 100], MediumSlow, Water, None),
+        ([35, 35, 40, 50, 35, 55], MediumSlow, Grass, Some(Flying)),
+        ([55, 45, 50, 80, 45, 65], MediumSlow, Grass, Some(Flying)),
+        ([75, 55, 70, 110, 55, 85], MediumSlow, Grass, Some(Flying)),
+        ([55, 70, 55, 85, 40, 55], Fast, Normal, None),
+        ([30, 30, 30, 30, 30, 30], MediumSlow, Grass, None),
+        ([75, 75, 55, 30, 105, 85], MediumSlow, Grass, None),
+        ([65, 65, 45, 95, 75, 45], MediumFast, Bug, Some(Flying)),
+        ([55, 45, 45, 15, 25, 25], MediumFast, Water, Some(Ground)),
+        ([95, 85, 85, 35, 65, 65], MediumFast, Water, Some(Ground)),
+        ([65, 65, 60, 110, 130, 95], MediumFast, Psychic, None),
+        ([95, 65, 110, 65, 60, 130], MediumFast, Dark, None),
+        ([60, 85, 42, 91, 85, 42], MediumSlow, Dark, Some(Flying)),
+        ([95, 75, 80, 30, 100, 110], MediumFast, Water, Some(Psychic)),
+        ([60, 60, 60, 85, 85, 85], Fast, Ghost, None),
+        ([48, 72, 48, 48, 72, 48], MediumFast, Psychic, None),
+        ([190, 33, 58, 33, 33, 58], MediumFast, Psychic, None),
+        ([70, 80, 65, 85, 90, 65], MediumFast, Normal, Some(Psychic)),
+        ([50, 65, 90, 15, 35, 35], MediumFast, Bug, None),
+        ([75, 90, 140, 40, 60, 60], MediumFast, Bug, Some(Steel)),
+        ([100, 70, 70, 45, 65, 65], MediumFast, Normal, None),
+        ([65, 75, 105, 85, 35, 65], MediumSlow, Ground, Some(Flying)),
+        ([75, 85, 200, 30, 55, 65], MediumFast, Steel, Some(Ground)),
+        ([60, 80, 50, 30, 40, 40], Fast, Normal, None),
+        ([90, 120, 75, 45, 60, 60], Fast, Normal, None),
+        ([65, 95, 75, 85, 55, 55], MediumFast, Water, Some(Poison)),
+        ([70, 130, 100, 65, 55, 80], MediumFast, Bug, Some(Steel)),
+        ([20, 10, 230, 5, 10, 230], MediumSlow, Bug, Some(Rock)),
+        ([80, 125, 75, 85, 40, 95], Slow, Bug, Some(Fighting)),
+        ([55, 95, 55, 115, 35, 75], MediumSlow, Dark, Some(Ice)),
+        ([60, 80, 50, 40, 50, 50], MediumFast, Normal, None),
+        ([90, 130, 75, 55, 75, 75], MediumFast, Normal, None),
+        ([40, 40, 40, 20, 70, 40], MediumFast, Fire, None),
+        ([50, 50, 120, 30, 80, 80], MediumFast, Fire, Some(Rock)),
+        ([50, 50, 40, 50, 30, 30], Slow, Ice, Some(Ground)),
+        ([100, 100, 80, 50, 60, 60], Slow, Ice, Some(Ground)),
+        ([55, 55, 85, 35, 65, 85], Fast, Water, Some(Rock)),
+        ([35, 65, 35, 65, 65, 35], MediumFast, Water, None),
+        ([75, 105, 75, 45, 105, 75], MediumFast, Water, None),
+        ([45, 55, 45, 75, 65, 45], Fast, Ice, Some(Flying)),
+        ([65, 40, 70, 70, 80, 140], Slow, Water, Some(Flying)),
+        ([65, 80, 140, 70, 40, 70], Slow, Steel, Some(Flying)),
+        ([45, 60, 30, 65, 80, 50], Slow, Dark, Some(Fire)),
+        ([75, 90, 50, 95, 110, 80], Slow, Dark, Some(Fire)),
+        ([75, 95, 95, 85, 95, 95], MediumFast, Water, Some(Dragon)),
+        ([90, 60, 60, 40, 40, 40], MediumFast, Ground, None),
+        ([90, 120, 120, 50, 60, 60], MediumFast, Ground, None),
+        ([85, 80, 90, 60, 105, 95], MediumFast, Normal, None),
+        ([73, 95, 62, 85, 85, 65], Slow, Normal, None),
+        ([55, 20, 35, 75, 20, 45], Fast, Normal, None),
+        ([35, 35, 35, 35, 35, 35], MediumFast, Fighting, None),
+        ([50, 95, 95, 70, 35, 110], MediumFast, Fighting, None),
+        ([45, 30, 15, 65, 85, 65], MediumFast, Ice, Some(Psychic)),
+        ([45, 63, 37, 95, 65, 55], MediumFast, Electric, None),
+        ([45, 75, 37, 83, 70, 55], MediumFast, Fire, None),
+        ([95, 80, 105, 100, 40, 70], Slow, Normal, None),
+        ([255, 10, 10, 55, 75, 135], Fast, Normal, None),
+        ([90, 85, 75, 115, 115, 100], Slow, Electric, None),
+        ([115, 115, 85, 100, 90, 75], Slow, Fire, None),
+        ([100, 75, 115, 85, 90, 115], Slow, Water, None),
+        ([50, 64, 50, 41, 45, 50], Slow, Rock, Some(Ground)),
+        ([70, 84, 70, 51, 65, 70], Slow, Rock, Some(Ground)),
+        ([100, 134, 110, 61, 95, 100], Slow, Rock, Some(Dark)),
+        ([106, 90, 130, 110, 90, 154], Slow, Psychic, Some(Flying)),
+        ([106, 130, 90, 90, 110, 154], Slow, Fire, Some(Flying)),
+        (
+            [100, 100, 100, 100, 100, 100],
+            MediumSlow,
+            Psychic,
+            Some(Grass),
+        ),
+        ([40, 45, 35, 70, 65, 55], MediumSlow, Grass, None),
+        ([50, 65, 45, 95, 85, 65], MediumSlow, Grass, None),
+        ([70, 85, 65, 120, 105, 85], MediumSlow, Grass, None),
+        ([45, 60, 40, 45, 70, 50], MediumSlow, Fire, None),
+        ([60, 85, 60, 55, 85, 60], MediumSlow, Fire, Some(Fighting)),
+        ([80, 120, 70, 80, 110, 70], MediumSlow, Fire, Some(Fighting)),
+        ([50, 70, 50, 40, 50, 50], MediumSlow, Water, None),
+        ([70, 85, 70, 50, 60, 70], MediumSlow, Water, Some(Ground)),
+        ([100, 110, 90, 60, 85, 90], MediumSlow, Water, Some(Ground)),
+        ([35, 55, 35, 35, 30, 30], MediumFast, Dark, None),
+        ([70, 90, 70, 70, 60, 60], MediumFast, Dark, None),
+        ([38, 30, 41, 60, 30, 41], MediumFast, Normal, None),
+        ([78, 70, 61, 100, 50, 61], MediumFast, Normal, None),
+        ([45, 45, 35, 20, 20, 30], MediumFast, Bug, None),
+        ([50, 35, 55, 15, 25, 25], MediumFast, Bug, None),
+        ([60, 70, 50, 65, 90, 50], MediumFast, Bug, Some(Flying)),
+        ([50, 35, 55, 15, 25, 25], MediumFast, Bug, None),
+        ([60, 50, 70, 65, 50, 90], MediumFast, Bug, Some(Poison)),
+        ([40, 30, 30, 30, 40, 50], MediumSlow, Water, Some(Grass)),
+        ([60, 50, 50, 50, 60, 70], MediumSlow, Water, Some(Grass)),
+        ([80, 70, 70, 70, 90, 100], MediumSlow, Water, Some(Grass)),
+        ([40, 40, 50, 30, 30, 30], MediumSlow, Grass, None),
+        ([70, 70, 40, 60, 60, 40], MediumSlow, Grass, Some(Dark)),
+        ([90, 100, 60, 80, 90, 60], MediumSlow, Grass, Some(Dark)),
+        ([40, 55, 30, 85, 30, 30], MediumSlow, Normal, Some(Flying)),
+        ([60, 85, 60, 125, 50, 50], MediumSlow, Normal, Some(Flying)),
+        ([40, 30, 30, 85, 55, 30], MediumFast, Water, Some(Flying)),
+        ([60, 50, 100, 65, 85, 70], MediumFast, Water, Some(Flying)),
+        ([28, 25, 25, 40, 45, 35], Slow, Psychic, None),
+        ([38, 35, 35, 50, 65, 55], Slow, Psychic, None),
+        ([68, 65, 65, 80, 125, 115], Slow, Psychic, None),
+        ([40, 30, 32, 65, 50, 52], MediumFast, Bug, Some(Water)),
+        ([70, 60, 62, 60, 80, 82], MediumFast, Bug, Some(Flying)),
+        ([60, 40, 60, 35, 40, 60], Fluctuating, Grass, None),
+        (
+            [60, 130, 80, 70, 60, 60],
+            Fluctuating,
+            Grass,
+            Some(Fighting),
+        ),
+        ([60, 60, 60, 30, 35, 35], Slow, Normal, None),
+        ([80, 80, 80, 90, 55, 55], Slow, Normal, None),
+        ([150, 160, 100, 100, 95, 65], Slow, Normal, None),
+        ([31, 45, 90, 40, 30, 30], Erratic, Bug, Some(Ground)),
+        ([61, 90, 45, 160, 50, 50], Erratic, Bug, Some(Flying)),
+        ([1, 90, 45, 40, 30, 30], Erratic, Bug, Some(Ghost)),
+        ([64, 51, 23, 28, 51, 23], MediumSlow, Normal, None),
+        ([84, 71, 43, 48, 71, 43], MediumSlow, Normal, None),
+        ([104, 91, 63, 68, 91, 63], MediumSlow, Normal, None),
+        ([72, 60, 30, 25, 20, 30], Fluctuating, Fighting, None),
+        ([144, 120, 60, 50, 40, 60], Fluctuating, Fighting, None),
+        ([50, 20, 40, 20, 20, 40], Fast, Normal, None),
+        ([30, 45, 135, 30, 45, 90], MediumFast, Rock, None),
+        ([50, 45, 45, 50, 35, 35], Fast, Normal, None),
+        ([70, 65, 65, 70, 55, 55], Fast, Normal, None),
+        ([50, 75, 75, 50, 65, 65], MediumSlow, Dark, Some(Ghost)),
+        ([50, 85, 85, 50, 55, 55], Fast, Steel, None),
+        ([50, 70, 100, 30, 40, 40], Slow, Steel, Some(Rock)),
+        ([60, 90, 140, 40, 50, 50], Slow, Steel, Some(Rock)),
+        ([70, 110, 180, 50, 60, 60], Slow, Steel, Some(Rock)),
+        (
+            [30, 40, 55, 60, 40, 55],
+            MediumFast,
+            Fighting,
+            Some(Psychic),
+        ),
+        (
+            [60, 60, 75, 80, 60, 75],
+            MediumFast,
+            Fighting,
+            Some(Psychic),
+        ),
+        ([40, 45, 40, 65, 65, 40], Slow, Electric, None),
+        ([70, 75, 60, 105, 105, 60], Slow, Electric, None),
+        ([60, 50, 40, 95, 85, 75], MediumFast, Electric, None),
+        ([60, 40, 50, 95, 75, 85], MediumFast, Electric, None),
+        ([65, 73, 55, 85, 47, 75], Erratic, Bug, None),
+        ([65, 47, 55, 85, 73, 75], Fluctuating, Bug, None),
+        ([50, 60, 45, 65, 100, 80], MediumSlow, Grass, Some(Poison)),
+        ([70, 43, 53, 40, 43, 53], Fluctuating, Poison, None),
+        ([100, 73, 83, 55, 73, 83], Fluctuating, Poison, None),
+        ([45, 90, 20, 65, 65, 20], Slow, Water, Some(Dark)),
+        ([70, 120, 40, 95, 95, 40], Slow, Water, Some(Dark)),
+        ([130, 70, 35, 60, 70, 35], Fluctuating, Water, None),
+        ([170, 90, 45, 60, 90, 45], Fluctuating, Water, None),
+        ([60, 60, 40, 35, 65, 45], MediumFast, Fire, Some(Ground)),
+        ([70, 100, 70, 40, 105, 75], MediumFast, Fire, Some(Ground)),
+        ([70, 85, 140, 20, 85, 70], MediumFast, Fire, None),
+        ([60, 25, 35, 60, 70, 80], Fast, Psychic, None),
+        ([80, 45, 65, 80, 90, 110], Fast, Psychic, None),
+        ([60, 60, 60, 60, 60, 60], Fast, Normal, None),
+        ([45, 100, 45, 10, 45, 45], MediumSlow, Ground, None),
+        ([50, 70, 50, 70, 50, 50], MediumSlow, Ground, Some(Dragon)),
+        ([80, 100, 80, 100, 80, 80], MediumSlow, Ground, Some(Dragon)),
+        ([50, 85, 40, 35, 85, 40], MediumSlow, Grass, None),
+        ([70, 115, 60, 55, 115, 60], MediumSlow, Grass, Some(Dark)),
+        ([45, 40, 60, 50, 40, 75], Erratic, Normal, Some(Flying)),
+        ([75, 70, 90, 80, 70, 105], Erratic, Dragon, Some(Flying)),
+        ([73, 115, 60, 90, 60, 60], Erratic, Normal, None),
+        ([73, 100, 60, 65, 100, 60], Fluctuating, Poison, None),
+        ([70, 55, 65, 70, 95, 85], Fast, Rock, Some(Psychic)),
+        ([70, 95, 85, 70, 55, 65], Fast, Rock, Some(Psychic)),
+        ([50, 48, 43, 60, 46, 41], MediumFast, Water, Some(Ground)),
+        ([110, 78, 73, 60, 76, 71], MediumFast, Water, Some(Ground)),
+        ([43, 80, 65, 35, 50, 35], Fluctuating, Water, None),
+        ([63, 120, 85, 55, 90, 55], Fluctuating, Water, Some(Dark)),
+        ([40, 40, 55, 55, 40, 70], MediumFast, Ground, Some(Psychic)),
+        (
+            [60, 70, 105, 75, 70, 120],
+            MediumFast,
+            Ground,
+            Some(Psychic),
+        ),
+        ([66, 41, 77, 23, 61, 87], Erratic, Rock, Some(Grass)),
+        ([86, 81, 97, 43, 81, 107], Erratic, Rock, Some(Grass)),
+        ([45, 95, 50, 75, 40, 50], Erratic, Rock, Some(Bug)),
+        ([75, 125, 100, 45, 70, 80], Erratic, Rock, Some(Bug)),
+        ([20, 15, 20, 80, 10, 55], Erratic, Water, None),
+        ([95, 60, 79, 81, 100, 125], Erratic, Water, None),
+        ([70, 70, 70, 70, 70, 70], MediumFast, Normal, None),
+        ([60, 90, 70, 40, 60, 120], MediumSlow, Normal, None),
+        ([44, 75, 35, 45, 63, 33], Fast, Ghost, None),
+        ([64, 115, 65, 65, 83, 63], Fast, Ghost, None),
+        ([20, 40, 90, 25, 30, 90], Fast, Ghost, None),
+        ([40, 70, 130, 25, 60, 130], Fast, Ghost, None),
+        ([99, 68, 83, 51, 72, 87], Slow, Grass, Some(Flying)),
+        ([65, 50, 70, 65, 95, 80], Fast, Psychic, None),
+        ([65, 130, 60, 75, 75, 60], MediumSlow, Dark, None),
+        ([95, 23, 48, 23, 23, 48], MediumFast, Psychic, None),
+        ([50, 50, 50, 50, 50, 50], MediumFast, Ice, None),
+        ([80, 80, 80, 80, 80, 80], MediumFast, Ice, None),
+        ([70, 40, 50, 25, 55, 50], MediumSlow, Ice, Some(Water)),
+        ([90, 60, 70, 45, 75, 70], MediumSlow, Ice, Some(Water)),
+        ([110, 80, 90, 65, 95, 90], MediumSlow, Ice, Some(Water)),
+        ([35, 64, 85, 32, 74, 55], Erratic, Water, None),
+        ([55, 104, 105, 52, 94, 75], Erratic, Water, None),
+        ([55, 84, 105, 52, 114, 75], Erratic, Water, None),
+        ([100, 90, 130, 55, 45, 65], Slow, Water, Some(Rock)),
+        ([43, 30, 55, 97, 40, 65], Fast, Water, None),
+        ([45, 75, 60, 50, 40, 30], Slow, Dragon, None),
+        ([65, 95, 100, 50, 60, 50], Slow, Dragon, None),
+        ([95, 135, 80, 100, 110, 80], Slow, Dragon, Some(Flying)),
+        ([40, 55, 80, 30, 35, 60], Slow, Steel, Some(Psychic)),
+        ([60, 75, 100, 50, 55, 80], Slow, Steel, Some(Psychic)),
+        ([80, 135, 130, 70, 95, 90], Slow, Steel, Some(Psychic)),
+        ([80, 100, 200, 50, 50, 100], Slow, Rock, None),
+        ([80, 50, 100, 50, 100, 200], Slow, Ice, None),
+        ([80, 75, 150, 50, 75, 150], Slow, Steel, None),
+        ([80, 80, 90, 110, 110, 130], Slow, Dragon, Some(Psychic)),
+        ([80, 90, 80, 110, 130, 110], Slow, Dragon, Some(Psychic)),
+        ([100, 100, 90, 90, 150, 140], Slow, Water, None),
+        ([100, 150, 140, 90, 100, 90], Slow, Ground, None),
+        ([105, 150, 90, 95, 150, 90], Slow, Dragon, Some(Flying)),
+        ([100, 100, 100, 100, 100, 100], Slow, Steel, Some(Psychic)),
+        ([50, 150, 50, 150, 150, 50], Slow, Psychic, None),
+    ]
+};
@@ -0,0 +1,101 @@
+use crate::mem::le as mem;
+
+use super::{DataMut, DataView, Game, SaveSlot, Section};
+
+/// Builds save files for tests.
+///
+/// Both save slots start with every section in canonical order and everything else zeroed.
+#[derive(Debug, Clone)]
+pub struct SaveBuilder {
+    bytes: Vec<u8>,
+    valid_signatures: [bool; 2],
+    valid_checksums: [bool; 2],
+}
+
+impl SaveBuilder {
+    pub fn new() -> Self {
+        let mut builder = Self {
+            bytes: vec![0; Game::SAVE_FILE_MIN_SIZE],
+            valid_signatures: [false; 2],
+            valid_checksums: [false; 2],
+        };
+
+        for slot in 0..2 {
+            for (section_index, id) in Section::VALID_IDS.into_iter().enumerate() {
+                builder.set_section_id(slot, section_index, id);
+            }
+        }
+
+        builder
+    }
+
+    pub fn set_section_id(&mut self, slot: usize, section_index: usize, id: u16) -> &mut Self {
+        let offset = Self::section_offset(slot, section_index) + Section::SECTION_ID_OFFSET;
+        mem::write_half_word(&mut self.bytes, offset, id);
+        self
+    }
+
+    /// Sets the save index of every section of the slot.
+    pub fn set_save_index(&mut self, slot: usize, save_index: u32) -> &mut Self {
+        for section_index in 0..SaveSlot::SECTION_COUNT {
+            let offset = Self::section_offset(slot, section_index) + Section::SAVE_INDEX_OFFSET;
+            mem::write_word(&mut self.bytes, offset, save_index);
+        }
+        self
+    }
+
+    /// Copies `data` into the section at the given position of the slot, starting at `offset`.
+    pub fn set_section_data(
+        &mut self,
+        slot: usize,
+        section_index: usize,
+        offset: usize,
+        data: &[u8],
+    ) -> &mut Self {
+        let offset = Self::section_offset(slot, section_index) + offset;
+        self.bytes[offset..(offset + data.len())].copy_from_slice(data);
+        self
+    }
+
+    /// Writes the magic signature to every section of the slot when building the save.
+    pub fn with_valid_signatures(&mut self, slot: usize) -> &mut Self {
+        self.valid_signatures[slot] = true;
+        self
+    }
+
+    /// Calculates the checksum of every section of the slot when building the save.
+    pub fn with_valid_checksums(&mut self, slot: usize) -> &mut Self {
+        self.valid_checksums[slot] = true;
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let mut bytes = self.bytes.clone();
+
+        for (slot, data) in bytes.chunks_exact_mut(SaveSlot::SIZE).take(2).enumerate() {
+            for section in data.chunks_exact_mut(Section::SIZE) {
+                if self.valid_signatures[slot] {
+                    mem::write_word(section, Section::SIGNATURE_OFFSET, Section::MAGIC_SIGNATURE);
+                }
+                let mut section = DataMut::<Section>::new(section);
+                // Sections with invalid IDs are left without a checksum.
+                if self.valid_checksums[slot] {
+                    section.update_checksum();
+                }
+            }
+        }
+
+        bytes
+    }
+
+    fn section_offset(slot: usize, section_index: usize) -> usize {
+        assert!(slot < 2, "there are only two save slots");
+        slot * SaveSlot::SIZE + section_index * Section::SIZE
+    }
+}
+
+impl Default for SaveBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
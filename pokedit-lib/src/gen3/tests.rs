@@ -0,0 +1,2008 @@
+use std::collections::HashMap;
+
+use super::{test_helpers::SaveBuilder, *};
+
+/// Creates a save whose first slot contains the sections in reverse order.
+fn reversed_save() -> Vec<u8> {
+    let mut builder = SaveBuilder::new();
+    for (section_index, id) in Section::VALID_IDS.into_iter().rev().enumerate() {
+        builder.set_section_id(0, section_index, id);
+    }
+    builder.build()
+}
+
+/// Creates a save with two valid slots, the version is chosen by `game_code`.
+fn new_save(game_code: u32) -> SaveBuilder {
+    let mut builder = SaveBuilder::new();
+    for slot in 0..2 {
+        builder
+            .set_section_data(
+                slot,
+                TrainerSection::ID as usize,
+                TrainerSection::GAME_CODE_OFFSET,
+                &game_code.to_le_bytes(),
+            )
+            .set_save_index(slot, slot as u32 + 1)
+            .with_valid_signatures(slot)
+            .with_valid_checksums(slot);
+    }
+    builder
+}
+
+fn section_ids(save_slot: Data<SaveSlot>) -> Vec<u16> {
+    save_slot.into_iter().map(|section| section.id()).collect()
+}
+
+#[test]
+fn rearrange_sections() {
+    let mut bytes = reversed_save();
+    // Tag the first byte of every section to make sure the contents move along with the ID.
+    for (i, section) in bytes[..SaveSlot::SIZE]
+        .chunks_exact_mut(Section::SIZE)
+        .enumerate()
+    {
+        section[0] = 13 - i as u8;
+    }
+
+    let mut save_slot = DataMut::<SaveSlot>::new(&mut bytes);
+    save_slot.rearrange_sections(Section::VALID_IDS).unwrap();
+
+    assert_eq!(
+        Section::VALID_IDS.to_vec(),
+        section_ids(save_slot.as_data())
+    );
+    for section in save_slot.as_data().sections() {
+        assert_eq!(section.id() as u8, section.data[0]);
+    }
+}
+
+#[test]
+fn rearrange_sections_rejects_duplicated_ids() {
+    let mut bytes = reversed_save();
+    let mut save_slot = DataMut::<SaveSlot>::new(&mut bytes);
+    let mut order = Section::VALID_IDS;
+    order[13] = 0;
+
+    assert!(save_slot.rearrange_sections(order).is_err());
+    assert_eq!(
+        Section::VALID_IDS.into_iter().rev().collect::<Vec<_>>(),
+        section_ids(save_slot.as_data())
+    );
+}
+
+#[test]
+fn item_quantity_is_decrypted_with_the_security_key() {
+    let version = GameVersion::Emerald;
+    let security_key = 0xABCD_1234;
+    let mut bytes = vec![0u8; TeamItemsSection::SIZE];
+    let offset = version.pocket_offset(PocketKind::PokeBalls) + TeamItemsSection::ITEM_SLOT_SIZE;
+    mem::write_half_word(&mut bytes, offset, 1);
+    mem::write_half_word(&mut bytes, offset + 2, 0x1234 ^ 3);
+
+    let team_items = Data::<TeamItemsSection>::new(&bytes).with_context(TeamItemsSection {
+        version,
+        security_key,
+    });
+
+    assert!(team_items.has_item(1));
+    assert_eq!(3, team_items.item_quantity(1));
+    assert!(!team_items.has_item(2));
+    assert_eq!(0, team_items.item_quantity(2));
+}
+
+#[test]
+fn bag_items() {
+    let version = GameVersion::Emerald;
+    let security_key = 0xABCD_1234;
+    let context = TeamItemsSection {
+        version,
+        security_key,
+    };
+    let mut bytes = vec![0u8; TeamItemsSection::SIZE];
+    let offset = version.pocket_offset(PocketKind::Berries);
+    mem::write_half_word(&mut bytes, offset, 133);
+    mem::write_half_word(&mut bytes, offset + 2, 0x1234 ^ 5);
+
+    let mut team_items = DataMut::<TeamItemsSection>::new(&mut bytes).with_context(context);
+    team_items
+        .set_item_quantity(PocketKind::Berries, 0, 42)
+        .unwrap();
+    assert!(team_items
+        .set_item_quantity(PocketKind::Berries, 0, 100)
+        .is_err());
+    assert!(team_items
+        .set_item_quantity(PocketKind::Berries, 1, 1)
+        .is_err());
+    assert!(matches!(
+        team_items.set_item_quantity(PocketKind::Berries, 46, 1),
+        Err(PkError::IndexOutOfBounds { .. })
+    ));
+
+    let bag = Data::<TeamItemsSection>::new(&bytes)
+        .with_context(context)
+        .bag_items();
+    assert_eq!(
+        &[Item {
+            id: 133,
+            quantity: 42
+        }],
+        bag.pocket(PocketKind::Berries)
+    );
+    assert!(bag.items.is_empty());
+    assert_eq!(0x1234 ^ 42, mem::read_half_word(&bytes, offset + 2));
+
+    let mut team_items = DataMut::<TeamItemsSection>::new(&mut bytes).with_context(context);
+    team_items
+        .set_item_quantity(PocketKind::Berries, 0, 0)
+        .unwrap();
+    let bag = Data::<TeamItemsSection>::new(&bytes)
+        .with_context(context)
+        .bag_items();
+    assert_eq!(Bag::default(), bag);
+}
+
+#[test]
+fn trainer_options() {
+    let mut bytes = vec![0u8; TrainerSection::SIZE];
+    bytes[TrainerSection::BUTTON_MODE_OFFSET] = 1;
+    bytes[TrainerSection::TEXT_OPTIONS_OFFSET] = (5 << 3) | 2;
+    bytes[TrainerSection::BATTLE_OPTIONS_OFFSET] = 0b101;
+
+    assert_eq!(
+        GameOptions {
+            button_mode: 1,
+            text_speed: 2,
+            frame: 5,
+            stereo_sound: true,
+            battle_style_set: false,
+            battle_scene_off: true,
+        },
+        Data::<TrainerSection>::new(&bytes).options()
+    );
+}
+
+#[test]
+fn section_type() {
+    let bytes = reversed_save();
+    let types = Data::<SaveSlot>::new(&bytes)
+        .sections()
+        .map(|section| section.section_type())
+        .collect::<Vec<_>>();
+
+    assert_eq!(SectionType::PcBuffer(11), types[0]);
+    assert_eq!(SectionType::PcBuffer(3), types[8]);
+    assert_eq!(SectionType::PcBuffer(2), types[9]);
+    assert_eq!(SectionType::PcBuffer(0), types[11]);
+    assert_eq!(SectionType::TeamItems, types[12]);
+    assert_eq!(SectionType::Trainer, types[13]);
+}
+
+#[test]
+fn import_and_export_box() {
+    let mut bytes = reversed_save();
+    let mut pokemon = [[0u8; PCStorage::POKEMON_SIZE]; PCStorage::BOX_SIZE];
+    for (i, pokemon) in pokemon.iter_mut().enumerate() {
+        pokemon.fill(i as u8 + 1);
+    }
+
+    // The last box is split between the last two sections.
+    let last_box = PCStorage::BOX_COUNT - 1;
+    let mut pc = PCStorageMut::new(DataMut::<SaveSlot>::new(&mut bytes)).unwrap();
+    pc.import_box(last_box, &pokemon).unwrap();
+
+    assert_eq!(
+        pokemon.to_vec(),
+        pc.as_storage().export_box(last_box).unwrap()
+    );
+    assert_eq!(
+        vec![[0; PCStorage::POKEMON_SIZE]; PCStorage::BOX_SIZE],
+        pc.as_storage().export_box(0).unwrap()
+    );
+    assert!(pc.import_box(PCStorage::BOX_COUNT, &pokemon).is_err());
+    assert!(pc.as_storage().export_box(PCStorage::BOX_COUNT).is_err());
+}
+
+mod properties {
+    use proptest::prelude::*;
+
+    use crate::gen3::{decrypt_word, encrypt_word, pokemon, DataView};
+
+    proptest! {
+        #[test]
+        fn pokemon_checksum_survives_encryption(
+            data in any::<[u8; pokemon::PokemonData::SUBSTRUCTURES_SIZE]>(),
+            personality in any::<u32>(),
+            ot_id in any::<u32>(),
+        ) {
+            let key = personality ^ ot_id;
+            let checksum = pokemon::checksum(&data);
+
+            let mut encrypted = data;
+            pokemon::encrypt_data(&mut encrypted, key);
+            let mut decrypted = encrypted;
+            pokemon::decrypt_data(&mut decrypted, key);
+            let mut re_encrypted = decrypted;
+            pokemon::encrypt_data(&mut re_encrypted, key);
+
+            prop_assert_eq!(data, decrypted);
+            prop_assert_eq!(encrypted, re_encrypted);
+            prop_assert_eq!(checksum, pokemon::checksum(&decrypted));
+        }
+
+        #[test]
+        fn pokemon_substructures_round_trip(raw in prop::array::uniform32(any::<u8>()).prop_flat_map(|header| {
+            prop::collection::vec(any::<u8>(), pokemon::PokemonData::SUBSTRUCTURES_SIZE)
+                .prop_map(move |substructures| {
+                    let mut raw = [0; pokemon::PokemonData::SIZE];
+                    raw[..32].copy_from_slice(&header);
+                    raw[32..].copy_from_slice(&substructures);
+                    raw
+                })
+        })) {
+            let pokemon = pokemon::DecryptedPokemon::decrypt(&raw);
+            let encrypted = pokemon.encrypt();
+            let decrypted = pokemon::DecryptedPokemon::decrypt(&encrypted);
+
+            prop_assert_eq!(&raw[pokemon::PokemonData::SUBSTRUCTURES_OFFSET..], &encrypted[pokemon::PokemonData::SUBSTRUCTURES_OFFSET..]);
+            prop_assert!(decrypted.is_checksum_valid());
+            prop_assert_eq!(pokemon.moves(), decrypted.moves());
+            prop_assert_eq!(pokemon.species(), decrypted.species());
+        }
+
+        #[test]
+        fn word_encryption_round_trips(value in any::<u32>(), key in any::<u32>()) {
+            prop_assert_eq!(value, decrypt_word(key, encrypt_word(key, value)));
+        }
+    }
+}
+
+#[test]
+fn save_round_trip() {
+    let path = std::env::temp_dir().join(format!("pokedit-round-trip-{}.sav", std::process::id()));
+    let mut bytes = new_save(0xDEAD_BEEF).build();
+
+    let mut game = Game::new_with_validation(&mut bytes, Validate::Full).unwrap();
+    assert_eq!(GameVersion::Emerald, game.version());
+    game.team_items_mut().set_money(12345).unwrap();
+    game.save(&path).unwrap();
+
+    let mut saved_bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let game = Game::new_with_validation(&mut saved_bytes, Validate::Full).unwrap();
+    assert_eq!(12345, game.team_items().money());
+    for section in game.save_slot().sections() {
+        assert_eq!(Some(section.checksum()), section.calculate_checksum());
+    }
+}
+
+#[test]
+fn badges_earned_count() {
+    // Emerald's first badge is the last bit of 0x137C in the save block 1, i.e. section 2.
+    let offset = 0x137C - Section::DATA_SIZE;
+    let mut bytes = new_save(0xDEAD_BEEF)
+        .set_section_data(1, 2, offset, &[0x80, 0b0000_0101])
+        .build();
+    let game = Game::new_bytes(&mut bytes).unwrap();
+
+    assert_eq!(3, game.badges_earned_count().unwrap());
+}
+
+#[test]
+fn playtime_seconds() {
+    let playtime = Playtime {
+        hours: 12,
+        minutes: 34,
+        seconds: 56,
+        frames: 0,
+    };
+
+    assert_eq!(45_296, playtime.total_seconds());
+    assert_eq!(playtime, Playtime::from_seconds(45_296));
+    assert_eq!(
+        Playtime {
+            hours: u16::MAX,
+            minutes: 59,
+            seconds: 59,
+            frames: 0,
+        },
+        Playtime::from_seconds(u32::MAX)
+    );
+}
+
+#[test]
+fn section_validation_report() {
+    let mut bytes = new_save(0).set_section_id(1, 3, 14).build();
+    let sections = Data::<SaveSlot>::from_offset(&bytes, SaveSlot::SIZE)
+        .sections()
+        .map(|section| section.validate_with_report())
+        .collect::<Vec<_>>();
+    assert!(sections[0].is_empty());
+    assert_eq!(
+        vec![SectionValidationIssue::InvalidSectionId(14)],
+        sections[3]
+    );
+
+    mem::write_word(&mut bytes, SaveSlot::SIZE + Section::SIGNATURE_OFFSET, 0);
+    mem::write_half_word(
+        &mut bytes,
+        SaveSlot::SIZE + Section::CHECKSUM_OFFSET,
+        0xBEEF,
+    );
+    let section = Data::<Section>::from_offset(&bytes, SaveSlot::SIZE);
+    let checksum = section.calculate_checksum().unwrap();
+    assert_eq!(
+        vec![
+            SectionValidationIssue::WrongChecksum {
+                found: 0xBEEF,
+                expected: checksum,
+            },
+            SectionValidationIssue::WrongSignature {
+                found: 0,
+                expected: Section::MAGIC_SIGNATURE,
+            },
+        ],
+        section.validate_with_report()
+    );
+}
+
+#[test]
+fn full_validation_report() {
+    let mut bytes = new_save(0).build();
+    // Corrupt the checksum of the section 5 of the backup save slot.
+    mem::write_half_word(
+        &mut bytes,
+        5 * Section::SIZE + Section::CHECKSUM_OFFSET,
+        0xBEEF,
+    );
+    let game = Game::new_with_validation(&mut bytes, Validate::None).unwrap();
+
+    let report = game.full_validation_report();
+    assert_eq!((SaveSlotKind::Current, vec![]), report[0]);
+    assert_eq!(SaveSlotKind::Backup, report[1].0);
+    assert_eq!(1, report[1].1.len());
+    assert_eq!(5, report[1].1[0].0);
+    assert!(matches!(
+        report[1].1[0].1[..],
+        [SectionValidationIssue::WrongChecksum { found: 0xBEEF, .. }]
+    ));
+}
+
+#[test]
+fn pkmdb_round_trip() {
+    let mut bytes = new_save(0).build();
+    let mut pokemon = [0u8; PCStorage::POKEMON_SIZE];
+    pokemon[pokemon::PokemonData::FLAGS_OFFSET] = pokemon::PokemonData::HAS_SPECIES_FLAG;
+    pokemon[0] = 0x42;
+
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    let mut pc = game.pc_storage_mut().unwrap();
+    let mut first_box = [[0; PCStorage::POKEMON_SIZE]; PCStorage::BOX_SIZE];
+    first_box[3] = pokemon;
+    pc.import_box(0, &first_box).unwrap();
+    let pkmdb = pc.as_storage().export_pkmdb();
+    assert_eq!(10 + 104, pkmdb.len());
+
+    pc.import_box(0, &[[0; PCStorage::POKEMON_SIZE]; PCStorage::BOX_SIZE])
+        .unwrap();
+    assert_eq!(1, pc.import_pkmdb(&pkmdb).unwrap());
+    assert_eq!(pokemon, pc.as_storage().pokemon_raw(0, 3).unwrap());
+
+    assert!(pc.import_pkmdb(&pkmdb[..pkmdb.len() - 1]).is_err());
+    assert!(pc.import_pkmdb(b"PKMN").is_err());
+}
+
+#[test]
+fn clone_pokemon() {
+    let mut bytes = new_save(0).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    let mut pc = game.pc_storage_mut().unwrap();
+    let mut first_box = [[0; PCStorage::POKEMON_SIZE]; PCStorage::BOX_SIZE];
+    first_box[0] = test_pokemon().encrypt();
+    pc.import_box(0, &first_box).unwrap();
+
+    pc.clone_pokemon(0, 0, 13, 29).unwrap();
+    assert_eq!(first_box[0], pc.as_storage().pokemon_raw(13, 29).unwrap());
+    assert!(matches!(
+        pc.clone_pokemon(13, 29, 0, 0),
+        Err(PkError::InvalidData("destination occupied"))
+    ));
+    assert!(pc.clone_pokemon(0, 0, 14, 0).is_err());
+}
+
+#[test]
+fn snapshot_and_re_encrypt_all_pokemon() {
+    let valid = test_pokemon().encrypt();
+    let mut stale = valid;
+    stale[PokemonData::CHECKSUM_OFFSET] ^= 0xFF;
+
+    let mut party_pokemon = [0; PartyPokemonData::SIZE];
+    party_pokemon[..PokemonData::SIZE].copy_from_slice(&valid);
+    let mut bytes = party_save(&[party_pokemon]);
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    let mut pc = game.pc_storage_mut().unwrap();
+    pc.set_pokemon_raw(2, 5, &stale).unwrap();
+
+    let snapshot = game.snapshot_all_pokemon().unwrap();
+    assert_eq!(
+        vec![PokemonLocation::Party(0), PokemonLocation::Box(2, 5)],
+        snapshot
+            .iter()
+            .map(|(location, _)| *location)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(33, snapshot[0].1.moves()[0]);
+
+    let before = game.data.to_vec();
+    game.re_encrypt_all_pokemon().unwrap();
+    assert_eq!(before, game.data.to_vec());
+}
+
+#[test]
+fn parse_action_replay_code() {
+    assert_eq!(
+        vec![(0x0202_5BC4, 0x3F), (0x0202_4EA4, 0x01)],
+        super::parse_action_replay_code("02025BC4 0000003F\n02024EA4 00000001").unwrap()
+    );
+    // 16-bit writes and hooks aren't supported.
+    assert!(super::parse_action_replay_code("12025BC4 0000FFFF").is_err());
+    assert!(super::parse_action_replay_code("E8C1C4A1 00000000").is_err());
+    assert!(super::parse_action_replay_code("02025BC4").is_err());
+    assert!(super::parse_action_replay_code("02025BC4 00000100").is_err());
+}
+
+#[test]
+fn apply_action_replay_code() {
+    let mut bytes = new_save(0).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+
+    // Ruby/Sapphire's money is at 0x0490 of the save block 1, which isn't encrypted.
+    game.apply_action_replay_code("02025BC4 0000003F 02025BC5 00000042")
+        .unwrap();
+    assert_eq!(0x423F, game.team_items().money());
+    assert!(game.apply_action_replay_code("03000000 00000001").is_err());
+
+    let mut bytes = new_save(0xDEAD_BEEF).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    assert!(matches!(
+        game.apply_action_replay_code("02025BC4 0000003F"),
+        Err(PkError::NotAvailableInGameVersion(_))
+    ));
+}
+
+#[test]
+fn pc_items() {
+    let mut bytes = new_save(1).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+
+    let mut pc_items = game.pc_items_mut();
+    pc_items.add_item(13, 5).unwrap();
+    pc_items.add_item(20, 1).unwrap();
+    pc_items.add_item(13, 10).unwrap();
+    assert!(pc_items.add_item(20, 999).is_err());
+    assert!(pc_items.remove_item(42, 1).is_err());
+    pc_items.remove_item(13, 15).unwrap();
+
+    let pc_items = game.pc_items();
+    assert_eq!(30, pc_items.capacity());
+    assert_eq!(
+        vec![Item {
+            id: 20,
+            quantity: 1
+        }],
+        pc_items.items().collect::<Vec<_>>()
+    );
+
+    let mut pc_items = game.pc_items_mut();
+    pc_items.set_item(1, items::ItemId::Leftovers, 999).unwrap();
+    assert!(pc_items.set_item(1, items::ItemId::Potion, 1000).is_err());
+    assert!(matches!(
+        pc_items.set_item(1, items::ItemId::MagmaEmblem, 1),
+        Err(PkError::NotAvailableInGameVersion("item"))
+    ));
+    assert!(matches!(
+        pc_items.set_item(30, items::ItemId::Potion, 1),
+        Err(PkError::IndexOutOfBounds { index: 30, .. })
+    ));
+    let pc_items = game.pc_items();
+    assert_eq!(
+        vec![
+            Item {
+                id: 20,
+                quantity: 1
+            },
+            Item {
+                id: 200,
+                quantity: 999
+            }
+        ],
+        pc_items.items().collect::<Vec<_>>()
+    );
+
+    let mut bytes = new_save(0).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    game.pc_items_mut().add_item(13, 5).unwrap();
+    let pc_items = game.pc_items();
+    assert_eq!(50, pc_items.capacity());
+    assert_eq!(
+        vec![Item {
+            id: 13,
+            quantity: 5
+        }],
+        pc_items.items().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        [13, 0, 5, 0],
+        bytes[SaveSlot::SIZE + TeamItemsSection::ID as usize * Section::SIZE + 0x0498..][..4]
+    );
+}
+
+#[test]
+fn find_pokemon_by_trainer_id() {
+    let ot_id = TrainerId {
+        public: 12345,
+        private: 54321,
+    };
+    let mut pokemon = [0; PokemonData::SIZE];
+    mem::write_half_word(&mut pokemon, PokemonData::PUBLIC_OT_ID_OFFSET, ot_id.public);
+    mem::write_half_word(
+        &mut pokemon,
+        PokemonData::PRIVATE_OT_ID_OFFSET,
+        ot_id.private,
+    );
+    pokemon[PokemonData::FLAGS_OFFSET] = PokemonData::HAS_SPECIES_FLAG;
+    let mut other_pokemon = pokemon;
+    other_pokemon[PokemonData::PUBLIC_OT_ID_OFFSET] ^= 1;
+
+    let version = GameVersion::Emerald;
+    let party_offset = version.party_offset();
+    let mut bytes = new_save(0xDEAD_BEEF)
+        .set_section_data(1, 1, version.team_size_offset(), &[2])
+        .set_section_data(1, 1, party_offset, &other_pokemon)
+        .set_section_data(1, 1, party_offset + PartyPokemonData::SIZE, &pokemon)
+        // Second slot of the first box.
+        .set_section_data(
+            1,
+            5,
+            PCStorage::POKEMON_OFFSET + PokemonData::SIZE,
+            &pokemon,
+        )
+        .build();
+    let game = Game::new_bytes(&mut bytes).unwrap();
+
+    assert_eq!(
+        vec![PokemonLocation::Party(1), PokemonLocation::Box(0, 1)],
+        game.find_pokemon_by_trainer_id(ot_id).unwrap()
+    );
+}
+
+/// Creates a Pokemon whose substructures are all zeroed, knowing Tackle and Growl.
+fn test_pokemon() -> DecryptedPokemon {
+    let mut raw = [0; PokemonData::SIZE];
+    // The attacks substructure is the last one, and the encryption key is 0 so that the zeroed
+    // substructures decrypt to zeroes.
+    mem::write_word(&mut raw, PokemonData::PERSONALITY_OFFSET, 23);
+    mem::write_word(&mut raw, PokemonData::OT_ID_OFFSET, 23);
+    raw[PokemonData::FLAGS_OFFSET] = PokemonData::HAS_SPECIES_FLAG;
+    let mut pokemon = DecryptedPokemon::decrypt(&raw);
+    pokemon.set_moves([33, 45, 0, 0]);
+    pokemon
+}
+
+/// Creates a FireRed/LeafGreen save with the given party.
+fn party_save(party: &[[u8; PartyPokemonData::SIZE]]) -> Vec<u8> {
+    let version = GameVersion::FireRedLeafGreen;
+    let mut builder = new_save(1);
+    builder.set_section_data(1, 1, version.team_size_offset(), &[party.len() as u8]);
+    for (index, party_pokemon) in party.iter().enumerate() {
+        let offset = version.party_offset() + index * PartyPokemonData::SIZE;
+        builder.set_section_data(1, 1, offset, party_pokemon);
+    }
+    builder.build()
+}
+
+#[test]
+fn heal_all_pokemon() {
+    let mut pokemon = test_pokemon();
+    pokemon.set_pp([1, 0, 0, 0]);
+
+    let mut party_pokemon = [0; PartyPokemonData::SIZE];
+    party_pokemon[..PokemonData::SIZE].copy_from_slice(&pokemon.encrypt());
+    mem::write_word(&mut party_pokemon, PartyPokemonData::STATUS_OFFSET, 0b1000);
+    mem::write_half_word(&mut party_pokemon, PartyPokemonData::CURRENT_HP_OFFSET, 3);
+    mem::write_half_word(&mut party_pokemon, PartyPokemonData::MAX_HP_OFFSET, 42);
+
+    let mut bytes = party_save(&[party_pokemon]);
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    game.heal_all_pokemon().unwrap();
+
+    let party_pokemon = game.team_items().party().next().unwrap();
+    let pokemon = party_pokemon.pokemon().decrypt();
+    assert_eq!(42, party_pokemon.current_hp());
+    assert_eq!([35, 40, 0, 0], pokemon.pp());
+    assert!(pokemon.is_checksum_valid());
+    assert_eq!(
+        0,
+        mem::read_word(party_pokemon.data, PartyPokemonData::STATUS_OFFSET)
+    );
+}
+
+#[test]
+fn max_out_evs() {
+    let mut party_pokemon = [0; PartyPokemonData::SIZE];
+    party_pokemon[..PokemonData::SIZE].copy_from_slice(&test_pokemon().encrypt());
+
+    let mut bytes = party_save(&[party_pokemon]);
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    game.max_out_evs(0, EvSpread::PHYSICAL_SWEEPER).unwrap();
+    assert!(matches!(
+        game.max_out_evs(1, EvSpread::BALANCED),
+        Err(PkError::IndexOutOfBounds { .. })
+    ));
+
+    let pokemon = game
+        .team_items()
+        .party()
+        .next()
+        .unwrap()
+        .pokemon()
+        .decrypt();
+    assert_eq!([4, 252, 0, 252, 0, 0], pokemon.evs());
+    assert_eq!(
+        508,
+        pokemon.evs().iter().map(|&ev| u16::from(ev)).sum::<u16>()
+    );
+    assert!(pokemon.is_checksum_valid());
+}
+
+#[test]
+fn pk3_round_trip() {
+    let mut party_pokemon = [0; PartyPokemonData::SIZE];
+    party_pokemon[..PokemonData::SIZE].copy_from_slice(&test_pokemon().encrypt());
+    party_pokemon[PartyPokemonData::LEVEL_OFFSET] = 50;
+
+    let mut bytes = party_save(&[party_pokemon, [0; PartyPokemonData::SIZE]]);
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    let pk3 = game.team_items().party().next().unwrap().to_pk3();
+    assert_eq!(
+        test_pokemon().moves(),
+        DecryptedPokemon::from_bytes(pk3[..PokemonData::SIZE].try_into().unwrap()).moves()
+    );
+
+    let mut team_items = game.team_items_mut();
+    let mut second = team_items.party_mut().nth(1).unwrap();
+    assert!(second.set_from_pk3(&pk3[..PokemonData::SIZE]).is_err());
+    second.set_from_pk3(&pk3).unwrap();
+
+    let mut party = game.team_items().party();
+    let (first, second) = (party.next().unwrap(), party.next().unwrap());
+    assert_eq!(first.data, second.data);
+}
+
+#[test]
+fn decode_text() {
+    assert_eq!("May", text::decode(&[0xC7, 0xD5, 0xED, 0xFF, 0x00]));
+    assert_eq!(
+        "Mr. 2!",
+        text::decode(&[0xC7, 0xE6, 0xAD, 0x00, 0xA3, 0xAB])
+    );
+}
+
+#[test]
+fn encode_padded_text() {
+    assert_eq!(
+        vec![0xC7, 0xD5, 0xED, 0xFF, 0xFF, 0xFF, 0xFF],
+        text::encode_padded("May", 7).unwrap()
+    );
+    assert_eq!(
+        "Mr. 2!",
+        text::decode(&text::encode_padded("Mr. 2!", 10).unwrap())
+    );
+    // There must be room for the terminator.
+    assert!(text::encode_padded("Brendan", 7).is_err());
+    assert!(text::encode_padded("Brenda", 7).is_ok());
+    assert!(text::encode_padded("日本", 7).is_err());
+}
+
+#[test]
+fn valid_text() {
+    assert!(text::is_valid(&[0xC7, 0xD5, 0xED, 0xFF, 0x00], 7));
+    assert!(text::is_valid(&[0xFF], 1));
+    // Missing terminator.
+    assert!(!text::is_valid(&[0xC7, 0xD5, 0xED, 0xFF], 3));
+    // Unknown character.
+    assert!(!text::is_valid(&[0xC7, 0x0A, 0xFF], 7));
+}
+
+#[test]
+fn player_name() {
+    let mut builder = new_save(1);
+    builder.set_section_data(1, 0, 0, &[0xC8, 0x1B, 0xD8, 0xF5, 0xFF, 0x00, 0x12]);
+    let mut bytes = builder.build();
+    let game = Game::new_bytes(&mut bytes).unwrap();
+    assert_eq!("Nédö", game.trainer().name().unwrap());
+
+    let mut builder = new_save(1);
+    builder.set_section_data(1, 0, 0, &[0xC8, 0x0A, 0xFF]);
+    let mut bytes = builder.build();
+    let game = Game::new_bytes(&mut bytes).unwrap();
+    assert!(matches!(
+        game.trainer().name(),
+        Err(PkError::InvalidData("player name charset"))
+    ));
+}
+
+#[test]
+fn set_player_name() {
+    let mut bytes = new_save(1).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    game.trainer_mut().set_name("May").unwrap();
+    assert_eq!(
+        [0xC7, 0xD5, 0xED, 0xFF, 0xFF, 0xFF, 0xFF],
+        game.trainer().name_raw()
+    );
+    // Clear the byte after the name, so the terminator must come from setting "Brendan".
+    game.trainer_mut().data[TrainerSection::PLAYER_NAME_LENGTH] = 0;
+    game.trainer_mut().set_name("Brendan").unwrap();
+    assert_eq!("Brendan", game.trainer().name().unwrap());
+    assert_eq!(
+        text::TERMINATOR,
+        game.trainer().data[TrainerSection::PLAYER_NAME_LENGTH]
+    );
+
+    assert!(game.trainer_mut().set_name("Brendans").is_err());
+    assert!(game.trainer_mut().set_name("日本").is_err());
+    assert_eq!("Brendan", game.trainer().name().unwrap());
+}
+
+#[test]
+fn set_player_gender() {
+    let mut bytes = new_save(1).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    game.trainer_mut().set_gender(Gender::Female).unwrap();
+    assert_eq!(Gender::Female, game.trainer().gender().unwrap());
+    assert!(game.trainer_mut().set_gender(Gender::Genderless).is_err());
+    assert_eq!(Gender::Female, game.trainer().gender().unwrap());
+}
+
+#[test]
+fn trainer_summary() {
+    let mut bytes = new_save(0).build();
+    let game = Game::new_bytes(&mut bytes).unwrap();
+    let summary = game.trainer().full_summary().unwrap();
+    assert_eq!(Gender::Male, summary.gender);
+    assert_eq!(None, summary.security_key);
+
+    let mut bytes = new_save(0xABCD_1234).build();
+    let game = Game::new_bytes(&mut bytes).unwrap();
+    let summary = game.trainer().full_summary().unwrap();
+    assert_eq!(0xABCD_1234, summary.game_code);
+    assert_eq!(Some(0xABCD_1234), summary.security_key);
+}
+
+#[test]
+fn set_trainer_id() {
+    let mut bytes = new_save(1).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    game.trainer_mut().set_trainer_id(TrainerId {
+        public: 12345,
+        private: 54321,
+    });
+    game.trainer_mut().set_trainer_id_private(7);
+    assert_eq!(
+        TrainerId {
+            public: 12345,
+            private: 7
+        },
+        game.trainer().trainer_id()
+    );
+    game.trainer_mut().set_trainer_id_public(1);
+    assert_eq!(1, game.trainer().trainer_id().public);
+}
+
+#[test]
+fn set_time_played() {
+    let mut bytes = new_save(1).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    let playtime = Playtime::new(999, 59, 30, 12).unwrap();
+    game.trainer_mut().set_time_played(playtime).unwrap();
+    assert_eq!(playtime, game.trainer().time_played());
+
+    assert!(Playtime::new(1, 60, 0, 0).is_err());
+    let invalid = Playtime {
+        frames: 60,
+        ..playtime
+    };
+    assert!(game.trainer_mut().set_time_played(invalid).is_err());
+    assert_eq!(playtime, game.trainer().time_played());
+}
+
+#[test]
+fn diff() {
+    let party_pokemon = |species| {
+        let mut pokemon = test_pokemon();
+        pokemon.set_species(species);
+        let mut party_pokemon = [0; PartyPokemonData::SIZE];
+        party_pokemon[..PokemonData::SIZE].copy_from_slice(&pokemon.encrypt());
+        party_pokemon
+    };
+    let mut before = party_save(&[party_pokemon(1)]);
+    let mut after = party_save(&[party_pokemon(4)]);
+    let mut before = Game::new_bytes(&mut before).unwrap();
+    let mut after = Game::new_bytes(&mut after).unwrap();
+    before.team_items_mut().set_money(100).unwrap();
+    after.team_items_mut().set_money(200).unwrap();
+
+    assert_eq!(
+        vec![
+            SaveChange::Money {
+                before: 100,
+                after: 200
+            },
+            SaveChange::PartyPokemon {
+                slot: 0,
+                before: 1,
+                after: 4
+            },
+        ],
+        before.diff(&after).unwrap()
+    );
+    assert!(before.diff(&before).unwrap().is_empty());
+}
+
+#[test]
+fn apparent_gender() {
+    let mut raw = [0; PokemonData::SIZE];
+    mem::write_word(&mut raw, PokemonData::PERSONALITY_OFFSET, 0x1234_5640);
+    let pokemon = Data::<PokemonData>::new(&raw);
+
+    assert_eq!(Gender::Genderless, pokemon.apparent_gender(255));
+    assert_eq!(Gender::Female, pokemon.apparent_gender(254));
+    assert_eq!(Gender::Male, pokemon.apparent_gender(0));
+    // 0x40 is below the 50% ratio but not the 12.5% one.
+    assert_eq!(Gender::Female, pokemon.apparent_gender(127));
+    assert_eq!(Gender::Male, pokemon.apparent_gender(31));
+}
+
+#[test]
+fn has_ever_saved() {
+    let mut bytes = new_save(0).build();
+    // Clear the backup slot as if the game had only been saved once.
+    bytes[..SaveSlot::SIZE].fill(0);
+    let game = Game::new_with_validation(&mut bytes, Validate::None).unwrap();
+
+    assert!(game.save_slot().has_ever_saved());
+    assert!(!game.backup_slot_ever_saved());
+}
+
+#[test]
+fn pokemon_language() {
+    assert_eq!(None, Language::from_pokemon_language(0xFF).unwrap());
+    assert_eq!(
+        Some(Language::Spanish),
+        Language::from_pokemon_language(7).unwrap()
+    );
+    assert!(Language::from_pokemon_language(6).is_err());
+    assert!(Language::try_from(0xFF).is_err());
+}
+
+#[test]
+fn parse_validate() {
+    assert_eq!(Validate::None, "none".parse().unwrap());
+    assert_eq!(Validate::Full, "Full".parse().unwrap());
+    assert_eq!(Validate::Repair, "repair".parse().unwrap());
+    assert!("fix".parse::<Validate>().is_err());
+    assert_eq!(
+        Validate::default(),
+        Validate::from_env("POKEDIT_TEST_UNSET_VALIDATION")
+    );
+}
+
+#[test]
+fn blank_section_count() {
+    let mut builder = new_save(0);
+    builder.set_section_data(1, 0, 0, &[1]);
+    let mut bytes = builder.build();
+    // Zero the signatures of the second slot, only its trainer section has data.
+    for section in bytes[SaveSlot::SIZE..(2 * SaveSlot::SIZE)].chunks_exact_mut(Section::SIZE) {
+        mem::write_word(section, Section::SIGNATURE_OFFSET, 0);
+    }
+    let game = Game::new_with_validation(&mut bytes, Validate::None).unwrap();
+
+    assert_eq!(
+        SaveSlot::SECTION_COUNT - 1,
+        game.save_slot().blank_section_count()
+    );
+    assert_eq!(0, game.backup_save_slot().blank_section_count());
+}
+
+#[test]
+fn default_security_key_keeps_version() {
+    // Emerald's key is read as the game code.
+    let key = GameVersion::Emerald.default_security_key();
+    assert_eq!(GameVersion::Emerald, GameVersion::from(key));
+}
+
+#[test]
+fn pokemon_count_by_species() {
+    let mut bytes = new_save(0).build();
+    let mut pokemon = [[0u8; PCStorage::POKEMON_SIZE]; PCStorage::BOX_SIZE];
+    for (raw, species) in pokemon.iter_mut().zip([25, 1, 25, 4, 1, 25]) {
+        let mut decrypted = test_pokemon();
+        decrypted.set_species(species);
+        *raw = decrypted.encrypt();
+    }
+    let mut pc = PCStorageMut::new(DataMut::<SaveSlot>::new(&mut bytes)).unwrap();
+    pc.import_box(3, &pokemon).unwrap();
+    let pc = pc.as_storage();
+
+    assert_eq!(
+        HashMap::from([(25, 3), (1, 2), (4, 1)]),
+        pc.pokemon_count_by_species()
+    );
+    assert_eq!(vec![(25, 3), (1, 2)], pc.most_common_species(2));
+}
+
+#[test]
+fn pal_park_eligibility_report() {
+    let mut surfer = test_pokemon();
+    surfer.set_species(1);
+    surfer.set_moves([33, 57, 0, 0]);
+    let mut eligible = test_pokemon();
+    eligible.set_species(1);
+
+    let mut party = [[0; PartyPokemonData::SIZE]; 2];
+    party[0][..PokemonData::SIZE].copy_from_slice(&surfer.encrypt());
+    party[1][..PokemonData::SIZE].copy_from_slice(&eligible.encrypt());
+    let mut bytes = party_save(&party);
+    let game = Game::new_bytes(&mut bytes).unwrap();
+
+    assert_eq!(
+        HashMap::from([(
+            PokemonLocation::Party(0),
+            vec![MigrationWarning::KnowsHm(57)]
+        )]),
+        game.pal_park_eligibility_report().unwrap()
+    );
+    assert_eq!(
+        vec![MigrationWarning::InvalidSpecies(0)],
+        check_pal_park_eligibility(&DecryptedPokemon::decrypt(&test_pokemon().encrypt()))
+    );
+    assert_eq!(
+        vec![MigrationWarning::InvalidChecksum],
+        check_pal_park_eligibility(&surfer)
+    );
+
+    let mut mail_holder = test_pokemon();
+    mail_holder.set_species(1);
+    mail_holder.set_held_item(items::ItemId::RetroMail.into());
+    assert_eq!(
+        vec![MigrationWarning::HoldsMail(132)],
+        check_pal_park_eligibility(&DecryptedPokemon::decrypt(&mail_holder.encrypt()))
+    );
+    mail_holder.set_held_item(items::ItemId::CheriBerry.into());
+    assert!(
+        check_pal_park_eligibility(&DecryptedPokemon::decrypt(&mail_holder.encrypt())).is_empty()
+    );
+}
+
+#[test]
+fn frlg_coins_follow_the_money() {
+    let version = GameVersion::FireRedLeafGreen;
+    let security_key = 0xABCD_1234;
+    let mut bytes = vec![0u8; TeamItemsSection::SIZE];
+    mem::write_word(&mut bytes, 0x0290, security_key ^ 500);
+    mem::write_half_word(&mut bytes, 0x0294, 0x1234 ^ 42);
+    let context = TeamItemsSection {
+        version,
+        security_key,
+    };
+
+    let team_items = Data::<TeamItemsSection>::new(&bytes).with_context(context);
+    assert_eq!(0x0294, TeamItemsSection::coins_offset(version));
+    assert_eq!(500, team_items.money());
+    assert_eq!(42, team_items.coins());
+
+    let mut team_items = DataMut::<TeamItemsSection>::new(&mut bytes).with_context(context);
+    team_items.set_coins(9999).unwrap();
+    assert!(matches!(
+        team_items.set_coins(10000),
+        Err(PkError::InvalidData("coins exceeds maximum"))
+    ));
+    assert_eq!(9999, team_items.as_data().coins());
+    assert_eq!(500, team_items.as_data().money());
+    assert!(matches!(
+        team_items.set_money(1_000_000),
+        Err(PkError::InvalidData("money exceeds maximum"))
+    ));
+    team_items.set_money(999_999).unwrap();
+    assert_eq!(999_999, team_items.as_data().money());
+    assert_eq!(0x1234 ^ 9999, mem::read_half_word(&bytes, 0x0294));
+}
+
+#[test]
+fn stamp_as_ot() {
+    let mut trainer = vec![0u8; TrainerSection::SIZE];
+    trainer[..TrainerSection::PLAYER_NAME_LENGTH]
+        .copy_from_slice(&[0xC7, 0xD5, 0xED, 0xFF, 0xFF, 0xFF, 0xFF]);
+    trainer[TrainerSection::GENDER_OFFSET] = 1;
+    mem::write_word(&mut trainer, TrainerSection::TRAINER_ID_OFFSET, 0x1234_5678);
+    let trainer = Data::<TrainerSection>::new(&trainer);
+
+    let mut pokemon = test_pokemon();
+    pokemon.set_species(25);
+    let mut raw = pokemon.encrypt();
+    trainer
+        .stamp_as_ot(&mut DataMut::<PokemonData>::new(&mut raw))
+        .unwrap();
+
+    let pokemon = DecryptedPokemon::decrypt(&raw);
+    assert_eq!(trainer.trainer_id(), pokemon.ot_id());
+    assert_eq!(trainer.name_raw(), pokemon.ot_name_raw());
+    assert_eq!(Gender::Female, pokemon.ot_gender());
+    assert_eq!(25, pokemon.species());
+    assert!(pokemon.is_checksum_valid());
+}
+
+#[test]
+fn to_party_bytes() {
+    let mut raw = test_pokemon().encrypt();
+    // Adamant.
+    mem::write_word(&mut raw, PokemonData::PERSONALITY_OFFSET, 3);
+    let mut pokemon = DecryptedPokemon::decrypt(&raw);
+    pokemon.set_species(445);
+    pokemon.set_experience(GrowthRate::Slow.experience(78) + 1);
+    pokemon.set_ivs([24, 12, 30, 5, 16, 23]);
+    pokemon.set_evs([74, 190, 91, 23, 48, 84]);
+    let base_stats = |species| {
+        assert_eq!(445, species);
+        BaseStats {
+            hp: 108,
+            attack: 130,
+            defense: 95,
+            speed: 102,
+            special_attack: 80,
+            special_defense: 85,
+            growth_rate: GrowthRate::Slow,
+        }
+    };
+
+    let bytes = pokemon.to_party_bytes(base_stats);
+    let party_pokemon = Data::<PartyPokemonData>::new(&bytes);
+    assert_eq!(78, party_pokemon.level());
+    assert_eq!(289, party_pokemon.max_hp());
+    assert_eq!(289, party_pokemon.current_hp());
+    assert_eq!(
+        [278, 193, 171, 135, 171],
+        std::array::from_fn(|stat| mem::read_half_word(
+            &bytes,
+            PartyPokemonData::STATS_OFFSET + stat * 2
+        ))
+    );
+    assert_eq!(pokemon.to_box_bytes(), bytes[..PokemonData::SIZE]);
+    assert_eq!([24, 12, 30, 5, 16, 23], pokemon.ivs());
+}
+
+#[test]
+fn calculated_stat() {
+    let mut raw = test_pokemon().encrypt();
+    // Adamant.
+    mem::write_word(&mut raw, PokemonData::PERSONALITY_OFFSET, 3);
+    let mut pokemon = DecryptedPokemon::decrypt(&raw);
+    pokemon.set_ivs([24, 12, 30, 5, 16, 23]);
+    pokemon.set_evs([74, 190, 91, 23, 48, 84]);
+
+    assert_eq!(289, pokemon.calculated_stat(StatKind::Hp, 108, 78));
+    assert_eq!(278, pokemon.calculated_stat(StatKind::Attack, 130, 78));
+    assert_eq!(193, pokemon.calculated_stat(StatKind::Defense, 95, 78));
+    assert_eq!(171, pokemon.calculated_stat(StatKind::Speed, 102, 78));
+    assert_eq!(
+        135,
+        pokemon.calculated_stat(StatKind::SpecialAttack, 80, 78)
+    );
+    assert_eq!(
+        171,
+        pokemon.calculated_stat(StatKind::SpecialDefense, 85, 78)
+    );
+    assert_eq!(1, pokemon.calculated_stat(StatKind::Hp, 1, 78));
+}
+
+#[test]
+fn growth_rates() {
+    assert_eq!(1_000_000, GrowthRate::MediumFast.experience(100));
+    assert_eq!(600_000, GrowthRate::Erratic.experience(100));
+    assert_eq!(1_640_000, GrowthRate::Fluctuating.experience(100));
+    assert_eq!(1_059_860, GrowthRate::MediumSlow.experience(100));
+    assert_eq!(800_000, GrowthRate::Fast.experience(100));
+    assert_eq!(1_250_000, GrowthRate::Slow.experience(100));
+    assert_eq!(0, GrowthRate::MediumSlow.experience(1));
+}
+
+#[test]
+fn repair_save_slots() {
+    let mut bytes = new_save(1).build();
+    let current = SaveSlot::SIZE;
+    mem::write_word(
+        &mut bytes,
+        current + 3 * Section::SIZE + Section::SIGNATURE_OFFSET,
+        0,
+    );
+    mem::write_half_word(
+        &mut bytes,
+        current + 5 * Section::SIZE + Section::CHECKSUM_OFFSET,
+        0xABCD,
+    );
+    mem::write_word(
+        &mut bytes,
+        7 * Section::SIZE + Section::SAVE_INDEX_OFFSET,
+        9,
+    );
+
+    let corrupted = bytes.clone();
+    let mut game = Game::new_with_validation(&mut bytes, Validate::None).unwrap();
+    assert_eq!(2, game.full_validation_report()[0].1.len());
+    game.repair_current_slot().unwrap();
+    game.repair_backup_slot().unwrap();
+    assert!(game
+        .full_validation_report()
+        .iter()
+        .all(|(_, report)| report.is_empty()));
+    assert!(game
+        .backup_save_slot()
+        .sections()
+        .all(|section| section.save_index() == 1));
+
+    Game::new_with_validation(&mut bytes, Validate::Full).unwrap();
+
+    assert!(Game::new_vec_with_validation(corrupted.clone(), Validate::Full).is_err());
+    let game = Game::new_vec_with_validation(corrupted, Validate::Repair).unwrap();
+    assert!(game
+        .full_validation_report()
+        .iter()
+        .all(|(_, report)| report.is_empty()));
+
+    // A slot that was never saved isn't turned into a valid looking one.
+    let mut builder = SaveBuilder::new();
+    builder
+        .set_save_index(1, 1)
+        .with_valid_signatures(1)
+        .with_valid_checksums(1);
+    let mut bytes = builder.build();
+    let blank_slot = bytes[..SaveSlot::SIZE].to_vec();
+    assert!(Game::new_with_validation(&mut bytes, Validate::Repair).is_err());
+    assert_eq!(blank_slot, bytes[..SaveSlot::SIZE]);
+}
+
+#[test]
+fn convert_to_little_endian() {
+    let expected = new_save(1).build();
+    let mut bytes = expected.clone();
+    for word in bytes.chunks_exact_mut(4) {
+        word.reverse();
+    }
+
+    super::convert_to_little_endian(&mut bytes).unwrap();
+    assert_eq!(expected, bytes);
+    // Little endian saves are left untouched.
+    super::convert_to_little_endian(&mut bytes).unwrap();
+    assert_eq!(expected, bytes);
+
+    for word in bytes.chunks_exact_mut(4) {
+        word.reverse();
+    }
+    let game = Game::new_bytes(&mut bytes).unwrap();
+    assert_eq!(GameVersion::FireRedLeafGreen, game.version());
+}
+
+#[test]
+fn wonder_card() {
+    let mut bytes = new_save(1).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    assert!(game.wonder_card().is_empty());
+
+    let mut name = [0xFF; WonderCardSection::EVENT_NAME_LENGTH];
+    name[..3].copy_from_slice(&[0xC7, 0xBF, 0xD1]);
+    game.wonder_card_mut()
+        .inject_event(151, &[1, 2, 3, 4])
+        .unwrap();
+    game.wonder_card_mut().set_event_name_raw(name);
+    let wonder_card = game.wonder_card();
+    assert!(!wonder_card.is_empty());
+    assert_eq!(151, wonder_card.event_id());
+    assert_eq!(name, wonder_card.event_name_raw());
+    assert_eq!(
+        [1, 2, 3, 4],
+        wonder_card.data[WonderCardSection::EVENT_DATA_OFFSET..][..4]
+    );
+
+    let too_big = [1; WonderCardSection::MAX_EVENT_DATA_SIZE + 1];
+    assert!(game.wonder_card_mut().inject_event(1, &too_big).is_err());
+    assert_eq!(151, game.wonder_card().event_id());
+}
+
+#[test]
+fn set_move_id() {
+    let mut pokemon = test_pokemon();
+    pokemon.set_move_id(2, 354).unwrap();
+    pokemon.set_move_id(0, 0).unwrap();
+    assert_eq!([0, 45, 354, 0], pokemon.moves());
+
+    assert!(matches!(
+        pokemon.set_move_id(1, 355),
+        Err(PkError::InvalidData(_))
+    ));
+    assert!(matches!(
+        pokemon.set_move_id(4, 1),
+        Err(PkError::IndexOutOfBounds { .. })
+    ));
+    assert_eq!([0, 45, 354, 0], pokemon.moves());
+}
+
+#[test]
+fn apply_ev_spread() {
+    assert_eq!(EvSpread::MAX_TOTAL, EvSpread::BALANCED.total());
+    assert!(EvSpread::BALANCED.is_valid());
+    for spread in [
+        EvSpread::PHYSICAL_SWEEPER,
+        EvSpread::SPECIAL_SWEEPER,
+        EvSpread::PHYSICAL_TANK,
+        EvSpread::SPECIAL_TANK,
+    ] {
+        assert_eq!(508, spread.total());
+        assert!(spread.is_valid());
+    }
+
+    let mut pokemon = test_pokemon();
+    pokemon.apply_ev_spread(EvSpread::SPECIAL_SWEEPER).unwrap();
+    assert_eq!([4, 0, 0, 252, 252, 0], pokemon.evs());
+
+    let too_many = EvSpread {
+        hp: 255,
+        speed: 255,
+        ..EvSpread::default()
+    };
+    assert!(!too_many.is_valid());
+    assert!(pokemon.apply_ev_spread(too_many).is_err());
+    assert_eq!([4, 0, 0, 252, 252, 0], pokemon.evs());
+}
+
+#[test]
+fn apply_iv_set() {
+    let mut pokemon = test_pokemon();
+    pokemon.apply_iv_set(IvSet::TRICK_ROOM_SLOW).unwrap();
+    assert_eq!([31, 31, 31, 0, 31, 31], pokemon.ivs());
+    pokemon.apply_iv_set(IvSet::ALL_31).unwrap();
+    assert_eq!([31; 6], pokemon.ivs());
+    // The IVs share their word with the egg and ability bits.
+    assert!(!pokemon.is_egg());
+
+    let invalid = IvSet {
+        sp_def: 32,
+        ..IvSet::SPECIAL_ATTACKER_NO_ATTACK
+    };
+    assert!(!invalid.is_valid());
+    assert!(pokemon.apply_iv_set(invalid).is_err());
+    assert_eq!([31; 6], pokemon.ivs());
+}
+
+#[test]
+fn save_slot_ids() {
+    let mut bytes = new_save(1).build();
+    let game = Game::new_bytes(&mut bytes).unwrap();
+    assert_eq!(SaveSlotId::B, game.current_save_slot_id());
+    assert_eq!(SaveSlotId::A, game.backup_save_slot_id());
+
+    let bytes = new_save(1).build();
+    let slot_a = Data::<SaveSlot>::new(&bytes);
+    let slot_b = Data::<SaveSlot>::from_offset(&bytes, SaveSlot::SIZE);
+    assert_eq!(Some(SaveSlotId::A), slot_a.save_slot_id(&bytes));
+    assert_eq!(Some(SaveSlotId::B), slot_b.save_slot_id(&bytes));
+    assert_eq!(None, slot_b.save_slot_id(&bytes[Section::SIZE..]));
+    assert_eq!(None, slot_a.save_slot_id(&bytes[SaveSlot::SIZE..]));
+}
+
+#[test]
+fn all_section_offsets() {
+    let bytes = reversed_save();
+    let info = Data::<SaveSlot>::new(&bytes).to_info(0x100);
+    let offsets = info.all_section_offsets();
+    assert_eq!(0x100 + 13 * Section::SIZE, offsets[0]);
+    assert_eq!(0x100, offsets[13]);
+}
+
+#[test]
+fn save_slot_index_wrapping() {
+    use std::cmp::Ordering;
+
+    assert_eq!(Ordering::Greater, save_slot_index_cmp(2, 1));
+    assert_eq!(Ordering::Less, save_slot_index_cmp(1, 2));
+    assert_eq!(Ordering::Equal, save_slot_index_cmp(7, 7));
+    assert_eq!(Ordering::Greater, save_slot_index_cmp(1, u32::MAX));
+    assert_eq!(Ordering::Less, save_slot_index_cmp(u32::MAX, 1));
+
+    let mut builder = new_save(1);
+    builder.set_save_index(0, 0).set_save_index(1, u32::MAX);
+    let mut bytes = builder
+        .with_valid_checksums(0)
+        .with_valid_checksums(1)
+        .build();
+    let game = Game::new_bytes(&mut bytes).unwrap();
+    assert_eq!(SaveSlotId::A, game.current_save_slot_id());
+}
+
+#[test]
+fn checksumable_byte_count() {
+    assert_eq!(
+        Some(3884),
+        Section::checksumable_byte_count(TrainerSection::ID)
+    );
+    assert_eq!(
+        Some(3968),
+        Section::checksumable_byte_count(TeamItemsSection::ID)
+    );
+    assert_eq!(Some(3848), Section::checksumable_byte_count(4));
+    assert_eq!(Some(2000), Section::checksumable_byte_count(13));
+    assert_eq!(None, Section::checksumable_byte_count(14));
+    assert!(Section::VALID_CHECKSUMABLE_SIZES
+        .iter()
+        .map(|&(id, _)| id)
+        .eq(Section::VALID_IDS));
+}
+
+#[test]
+fn verify_save_integrity() {
+    let path = std::env::temp_dir().join(format!("pokedit-verify-{}.sav", std::process::id()));
+    let mut bytes = new_save(1).build();
+    std::fs::write(&path, &bytes).unwrap();
+    super::verify_save_integrity(&path).unwrap();
+
+    bytes[0] ^= 0xFF;
+    std::fs::write(&path, &bytes).unwrap();
+    let result = super::verify_save_integrity(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        result,
+        Err(PkError::Load(PkErrorLoad::InvalidChecksum { .. }))
+    ));
+    assert!(matches!(
+        super::verify_save_integrity(&path),
+        Err(PkError::Io(_))
+    ));
+}
+
+#[test]
+fn status_condition() {
+    let status = StatusCondition::new(0b1000_0011);
+    assert!(status.is_sleep());
+    assert_eq!(3, status.sleep_turns_remaining());
+    assert!(status.is_badly_poisoned());
+    assert!(!status.is_poisoned() && !status.is_burned() && !status.is_frozen());
+    assert!(!status.is_ok());
+    assert!(status.cure().is_ok());
+
+    let mut status = StatusCondition::default();
+    status.set_paralyzed(true);
+    status.set_sleep_turns_remaining(9);
+    assert_eq!(0b0100_0111, status.raw());
+    status.set_paralyzed(false);
+    status.set_sleep_turns_remaining(0);
+    assert!(status.is_ok());
+
+    let mut party_pokemon = [0; PartyPokemonData::SIZE];
+    let mut data = DataMut::<PartyPokemonData>::new(&mut party_pokemon);
+    data.set_status(StatusCondition::new(1 << 4));
+    assert!(data.as_data().status().is_burned());
+}
+
+#[test]
+fn check_version_consistency() {
+    let mut bytes = new_save(1).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    game.check_version_consistency().unwrap();
+
+    let offset = game
+        .current_save_slot_info
+        .section_offset(TrainerSection::ID)
+        + TrainerSection::GAME_CODE_OFFSET;
+    mem::write_word(&mut game.data, offset, 0);
+    assert!(matches!(
+        game.check_version_consistency(),
+        Err(PkError::InvalidData("version_consistency"))
+    ));
+}
+
+#[test]
+fn iterate_save_slot() {
+    let mut bytes = new_save(1).build();
+    let game = Game::new_bytes(&mut bytes).unwrap();
+    let mut count = 0;
+    for section in game.save_slot() {
+        assert_eq!(Section::MAGIC_SIGNATURE, section.signature());
+        count += 1;
+    }
+    assert_eq!(SaveSlot::SECTION_COUNT, count);
+}
+
+#[test]
+fn section_by_id_mut() {
+    let mut bytes = reversed_save();
+    let mut save_slot = DataMut::<SaveSlot>::new(&mut bytes[..SaveSlot::SIZE]);
+    let section = save_slot.section_by_id_mut(2).unwrap();
+    section.data[0] = 0x42;
+    assert_eq!(0x42, bytes[11 * Section::SIZE]);
+
+    let mut save_slot = DataMut::<SaveSlot>::new(&mut bytes[..SaveSlot::SIZE]);
+    assert!(save_slot.section_by_id_mut(14).is_none());
+
+    let mut builder = new_save(1);
+    for (section_index, id) in Section::VALID_IDS.into_iter().rev().enumerate() {
+        builder.set_section_id(1, section_index, id);
+    }
+    let mut bytes = builder.with_valid_checksums(1).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    let mut save_slot = game.save_slot_mut();
+    let section = save_slot.section_by_id_mut(TeamItemsSection::ID).unwrap();
+    assert_eq!(TeamItemsSection::ID, section.as_data().id());
+    section.data[0] = 0x42;
+    assert!(save_slot.section_by_id_mut(14).is_none());
+    // The cached offsets follow the sections around.
+    save_slot.rearrange_sections(Section::VALID_IDS).unwrap();
+    let section = save_slot.section_by_id_mut(TeamItemsSection::ID).unwrap();
+    assert_eq!(0x42, section.data[0]);
+    assert_eq!(0x42, game.save_slot().sections().nth(1).unwrap().data[0]);
+}
+
+#[test]
+fn game_sections() {
+    let mut builder = new_save(0);
+    for slot in 0..2 {
+        for (section_index, id) in Section::VALID_IDS.into_iter().rev().enumerate() {
+            builder.set_section_id(slot, section_index, id);
+        }
+    }
+    let mut bytes = builder.build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    for id in 0..SaveSlot::SECTION_COUNT as u16 {
+        assert_eq!(id, game.section(id).unwrap().id());
+    }
+    assert!(matches!(
+        game.section(14),
+        Err(PkError::IndexOutOfBounds { index: 14, .. })
+    ));
+
+    game.section_mut(5).unwrap().data[0] = 0x42;
+    assert_eq!(0x42, game.section(5).unwrap().data[0]);
+    assert_eq!(0x42, game.save_slot().sections().nth(8).unwrap().data[0]);
+    assert!(game.section_mut(14).is_err());
+}
+
+#[test]
+fn current_map() {
+    let mut builder = new_save(1);
+    builder.set_section_data(1, 1, TeamItemsSection::MAP_BANK_OFFSET, &[3, 19]);
+    let mut bytes = builder.build();
+    let game = Game::new_bytes(&mut bytes).unwrap();
+    let map = game.team_items().current_map();
+    assert_eq!(
+        MapLocation {
+            bank: 3,
+            number: 19
+        },
+        map
+    );
+    assert_eq!(Some("Route 1"), map.to_name(game.version()));
+    assert_eq!(None, map.to_name(GameVersion::Emerald));
+    assert_eq!(
+        Some("Littleroot Town"),
+        MapLocation { bank: 0, number: 9 }.to_name(GameVersion::RubySapphire)
+    );
+}
+
+#[test]
+fn repel_steps() {
+    for game_code in [0, 1, 2] {
+        let mut bytes = new_save(game_code).build();
+        let mut game = Game::new_bytes(&mut bytes).unwrap();
+        assert_eq!(0, game.repel_steps_remaining().unwrap());
+        game.set_repel_steps(250).unwrap();
+        assert_eq!(250, game.repel_steps_remaining().unwrap());
+
+        let var_offset = game.version().vars_offset() + 0x21 * 2;
+        let section = game
+            .save_slot()
+            .sections()
+            .nth(1 + var_offset / Section::DATA_SIZE)
+            .unwrap();
+        let var_offset = var_offset % Section::DATA_SIZE;
+        let expected = match game.version() {
+            GameVersion::FireRedLeafGreen => 0,
+            _ => 250,
+        };
+        assert_eq!(expected, section.data[var_offset]);
+    }
+}
+
+#[test]
+fn party_nickname() {
+    let mut pokemon = test_pokemon().encrypt();
+    pokemon[PokemonData::NICKNAME_OFFSET..(PokemonData::NICKNAME_OFFSET + 4)]
+        .copy_from_slice(&[0xBE, 0xBB, 0xCE, 0xFF]);
+    let mut party_pokemon = [0; PartyPokemonData::SIZE];
+    party_pokemon[..PokemonData::SIZE].copy_from_slice(&pokemon);
+    let mut bytes = party_save(&[party_pokemon]);
+    let game = Game::new_bytes(&mut bytes).unwrap();
+
+    let party = game.team_items().party().collect::<Vec<_>>();
+    assert_eq!(1, party.len());
+    let nickname = party[0].pokemon().nickname_raw();
+    assert_eq!("DAT", text::decode(&nickname));
+    assert_eq!(33, party[0].pokemon().decrypt().moves()[0]);
+}
+
+#[test]
+fn substructure_shuffle() {
+    let mut data = [0; PokemonData::SIZE];
+    for (index, substructure) in data[PokemonData::SUBSTRUCTURES_OFFSET..]
+        .chunks_exact_mut(DecryptedPokemon::SUBSTRUCTURE_SIZE)
+        .enumerate()
+    {
+        substructure.fill(index as u8);
+    }
+
+    // Personality 9 stores the substructures as attacks, EVs, misc, growth.
+    let shuffled = pokemon::encrypt_substructures(&data, 9);
+    let order = shuffled[PokemonData::SUBSTRUCTURES_OFFSET..]
+        .chunks_exact(DecryptedPokemon::SUBSTRUCTURE_SIZE)
+        .map(|substructure| substructure[0])
+        .collect::<Vec<_>>();
+    assert_eq!(vec![1, 2, 3, 0], order);
+
+    for personality in 0..24 {
+        data[..4].copy_from_slice(&u32::to_le_bytes(personality));
+        let shuffled = pokemon::encrypt_substructures(&data, personality);
+        assert_eq!(data, pokemon::decrypt_substructures(&shuffled));
+    }
+}
+
+#[test]
+fn shiny() {
+    let ot_id = TrainerId {
+        public: 24294,
+        private: 38834,
+    };
+    // 24294 ^ 38834 = 0xC954, so the personality halves must XOR to something within 7 of it.
+    assert!(pokemon::is_shiny(0xC954_0000, ot_id));
+    assert!(pokemon::is_shiny(0x0000_C953, ot_id));
+    assert!(pokemon::is_shiny(0x1234_DB67, ot_id));
+    assert!(!pokemon::is_shiny(0x1234_DB68, ot_id));
+    assert!(!pokemon::is_shiny(0, ot_id));
+
+    let mut pokemon = test_pokemon();
+    pokemon.set_ot_id(ot_id);
+    let mut raw = pokemon.encrypt();
+    mem::write_word(&mut raw, PokemonData::PERSONALITY_OFFSET, 0xC954_0000);
+    let mut party_pokemon = [0; PartyPokemonData::SIZE];
+    party_pokemon[..PokemonData::SIZE].copy_from_slice(&raw);
+    let mut bytes = party_save(&[party_pokemon]);
+    let game = Game::new_bytes(&mut bytes).unwrap();
+
+    assert!(game.party_pokemon(0).unwrap().pokemon().is_shiny());
+    assert!(matches!(
+        game.party_pokemon(1),
+        Err(PkError::IndexOutOfBounds {
+            index: 1,
+            len: 1,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn species_enum() {
+    use species::Species;
+
+    for species_id in 0..species::COUNT as u16 {
+        let species = Species::from(species_id);
+        assert_eq!(species_id, u16::from(species));
+        assert_eq!(
+            species::is_valid(species_id),
+            species.national_dex().is_some()
+        );
+    }
+    for (index, species) in Species::ALL.into_iter().enumerate() {
+        assert_eq!(Some(index as u16 + 1), species.national_dex());
+    }
+
+    assert_eq!(Species::Bulbasaur, Species::from(1));
+    assert_eq!(Species::Celebi, Species::from(251));
+    assert_eq!(Species::Unknown(252), Species::from(252));
+    assert_eq!(Species::Treecko, Species::from(277));
+    assert_eq!(Some(252), Species::Treecko.national_dex());
+    assert_eq!(Species::Chimecho, Species::from(411));
+    assert_eq!(Some(358), Species::Chimecho.national_dex());
+    assert_eq!(Species::Unknown(species::EGG), Species::from(species::EGG));
+    assert_eq!(Some("Chimecho"), Species::Chimecho.name());
+    assert_eq!(None, Species::Unknown(0).name());
+
+    assert_eq!(
+        Some((Type::Electric, Some(Type::Steel))),
+        Species::Magnemite.types()
+    );
+    assert_eq!(Some((Type::Normal, None)), Species::Clefairy.types());
+    assert_eq!(None, Species::Unknown(0).types());
+    assert_eq!(
+        Some(BaseStats {
+            hp: 1,
+            attack: 90,
+            defense: 45,
+            speed: 40,
+            special_attack: 30,
+            special_defense: 30,
+            growth_rate: GrowthRate::Erratic,
+        }),
+        Species::Shedinja.base_stats()
+    );
+    assert_eq!(None, Species::Unknown(0).base_stats());
+}
+
+#[test]
+fn item_ids() {
+    use items::ItemId;
+
+    for item in ItemId::ALL {
+        assert_eq!(item, ItemId::try_from(u16::from(item)).unwrap());
+    }
+    assert!(ItemId::ALL.windows(2).all(|items| items[0] < items[1]));
+    assert!(ItemId::try_from(0).is_err());
+    assert!(ItemId::try_from(52).is_err());
+    assert!(ItemId::try_from(377).is_err());
+
+    assert_eq!(4, u16::from(ItemId::PokeBall));
+    assert_eq!("Poké Ball", ItemId::PokeBall.name());
+    assert_eq!(Some("Leftovers"), items::name(200));
+    assert_eq!(Some("TM01"), items::name(289));
+    assert_eq!(Some("HM08"), items::name(346));
+    assert_eq!(Some("Old Sea Map"), items::name(376));
+    assert_eq!(None, items::name(347));
+
+    assert!(GameVersion::RubySapphire.has_item(ItemId::Hm08));
+    assert!(!GameVersion::RubySapphire.has_item(ItemId::OaksParcel));
+    assert!(GameVersion::FireRedLeafGreen.has_item(ItemId::Sapphire));
+    assert!(!GameVersion::FireRedLeafGreen.has_item(ItemId::MagmaEmblem));
+    assert!(GameVersion::Emerald.has_item(ItemId::OldSeaMap));
+}
+
+#[test]
+fn battle_points() {
+    for game_code in [0, 1] {
+        let mut bytes = new_save(game_code).build();
+        let mut game = Game::new_bytes(&mut bytes).unwrap();
+        assert!(matches!(
+            game.battle_points(),
+            Err(PkError::NotAvailableInGameVersion("Battle Points"))
+        ));
+        assert!(game.set_battle_points(1).is_err());
+    }
+
+    let mut bytes = new_save(2).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    assert_eq!(0, game.battle_points().unwrap());
+    game.set_battle_points(9999).unwrap();
+    assert_eq!(9999, game.battle_points().unwrap());
+    assert_eq!(
+        9999,
+        mem::read_half_word(
+            game.trainer().data,
+            TrainerSection::EMERALD_BATTLE_POINTS_OFFSET
+        )
+    );
+}
+
+#[test]
+fn pokedex_flags() {
+    let mut bytes = new_save(2).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+
+    let mut pokedex = game.pokedex_mut();
+    pokedex.set_caught(Species::Bulbasaur, true).unwrap();
+    pokedex.set_caught(Species::Celebi, true).unwrap();
+    pokedex.set_caught(Species::Deoxys, true).unwrap();
+    assert!(pokedex.set_caught(Species::Unknown(0), true).is_err());
+    game.set_pokedex_seen(Species::Deoxys, true).unwrap();
+    game.set_pokedex_seen(Species::Treecko, true).unwrap();
+    game.set_pokedex_seen(Species::Treecko, false).unwrap();
+
+    let pokedex = game.pokedex();
+    assert!(pokedex.caught(Species::Celebi));
+    assert!(!pokedex.caught(Species::Treecko));
+    assert!(pokedex.seen(Species::Deoxys));
+    assert!(!pokedex.seen(Species::Treecko));
+    assert!(!pokedex.seen(Species::Unknown(0)));
+
+    let trainer = game.trainer();
+    assert_eq!(3, trainer.pokedex_owned_count());
+    assert_eq!(1, trainer.pokedex_seen_count());
+    // Species 251 is bit 2 of byte 31, species 386 is bit 1 of byte 48.
+    assert_eq!(
+        0b0000_0001,
+        trainer.data[TrainerSection::POKEDEX_OWNED_OFFSET]
+    );
+    assert_eq!(
+        0b0000_0100,
+        trainer.data[TrainerSection::POKEDEX_OWNED_OFFSET + 31]
+    );
+    assert_eq!(
+        0b0000_0010,
+        trainer.data[TrainerSection::POKEDEX_SEEN_OFFSET + 48]
+    );
+
+    for copy_offset in GameVersion::Emerald.pokedex_seen_copy_offsets() {
+        let offset = copy_offset + 48;
+        let section = game
+            .save_slot()
+            .sections()
+            .nth(1 + offset / Section::DATA_SIZE)
+            .unwrap();
+        assert_eq!(0b0000_0010, section.data[offset % Section::DATA_SIZE]);
+    }
+}
+
+#[test]
+fn pokedex_completion() {
+    let mut bytes = new_save(1).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+
+    let mut pokedex = game.pokedex_mut();
+    for species in [Species::Bulbasaur, Species::Mew, Species::Deoxys] {
+        pokedex.set_caught(species, true).unwrap();
+        pokedex.set_seen(species, true).unwrap();
+    }
+    pokedex.set_seen(Species::Treecko, true).unwrap();
+    // The unused bits after Deoxys must not be counted.
+    pokedex.data[PokedexSection::SEEN_OFFSET + 48] |= 0b1111_1100;
+
+    let pokedex = game.pokedex();
+    assert_eq!(3, pokedex.caught_count());
+    assert_eq!(4, pokedex.seen_count());
+    assert!((pokedex.completion_percentage() - 300.0 / 386.0).abs() < f32::EPSILON);
+    assert_eq!(4, game.trainer().pokedex_seen_count());
+
+    assert!(!game.national_pokedex_unlocked().unwrap());
+    let (magic_offset, magic) = game.version().national_pokedex_magic();
+    let (var, value) = game.version().national_pokedex_var();
+    let flag = game.version().national_pokedex_flag();
+    game.trainer_mut().data[magic_offset] = magic;
+    game.set_var(var, value).unwrap();
+    assert!(!game.national_pokedex_unlocked().unwrap());
+    let flag_offset =
+        game.save_block1_offset(game.version().flags_offset() + usize::from(flag / 8));
+    game.data[flag_offset] |= 1 << (flag % 8);
+    assert!(game.national_pokedex_unlocked().unwrap());
+}
+
+#[test]
+fn pc_box() {
+    let mut bytes = new_save(2).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    let mut last_box = [[0; PCStorage::POKEMON_SIZE]; PCStorage::BOX_SIZE];
+    last_box[29] = test_pokemon().encrypt();
+    game.pc_storage_mut()
+        .unwrap()
+        .import_box(13, &last_box)
+        .unwrap();
+    // The box names are stored in the last PC section.
+    let name_offset = PCStorage::BOX_NAMES_OFFSET + 13 * PCStorage::BOX_NAME_LENGTH;
+    let section = game
+        .section_mut(PCStorage::FIRST_SECTION_ID + (name_offset / Section::DATA_SIZE) as u16)
+        .unwrap();
+    let name = text::encode_padded("BOX14", PCStorage::BOX_NAME_LENGTH).unwrap();
+    let start = name_offset % Section::DATA_SIZE;
+    section.data[start..(start + name.len())].copy_from_slice(&name);
+
+    let pc_box = game.pc_box(13).unwrap();
+    assert_eq!("BOX14", pc_box.name());
+    assert_eq!(
+        Some(DecryptedPokemon::decrypt(&last_box[29])),
+        pc_box.pokemon(29)
+    );
+    assert_eq!(None, pc_box.pokemon(0));
+    assert_eq!(None, pc_box.pokemon(PCStorage::BOX_SIZE));
+    assert!(matches!(
+        game.pc_box(PCStorage::BOX_COUNT),
+        Err(PkError::IndexOutOfBounds { name: "box", .. })
+    ));
+}
+
+#[test]
+fn stored_pokemon() {
+    let mut bulbasaur = test_pokemon();
+    bulbasaur.set_species(u16::from(Species::Bulbasaur));
+    let mut party_pokemon = [0; PartyPokemonData::SIZE];
+    party_pokemon[..PokemonData::SIZE].copy_from_slice(&bulbasaur.encrypt());
+    let mut bytes = party_save(&[party_pokemon]);
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+
+    let mut pikachu = test_pokemon();
+    pikachu.set_species(u16::from(Species::Pikachu));
+    let mut mew_egg = test_pokemon();
+    mew_egg.set_species(u16::from(Species::Mew));
+    let mut mew_egg = mew_egg.to_bytes();
+    mem::write_word(
+        &mut mew_egg,
+        DecryptedPokemon::IVS_OFFSET,
+        1 << DecryptedPokemon::IS_EGG_BIT,
+    );
+    let mut pc = game.pc_storage_mut().unwrap();
+    pc.set_pokemon_raw(0, 4, &pikachu.encrypt()).unwrap();
+    pc.set_pokemon_raw(13, 29, &DecryptedPokemon::from_bytes(mew_egg).encrypt())
+        .unwrap();
+
+    assert_eq!(2, game.total_stored_pokemon().unwrap());
+    assert!(game.has_pokemon(Species::Bulbasaur).unwrap());
+    assert!(game.has_pokemon(Species::Pikachu).unwrap());
+    assert!(!game.has_pokemon(Species::Mew).unwrap());
+    assert!(!game.has_pokemon(Species::Charmander).unwrap());
+}
+
+#[test]
+fn pkm_export() {
+    let path = std::env::temp_dir().join(format!("pokedit-export-{}.pkm", std::process::id()));
+    let mut party_pokemon = [0; PartyPokemonData::SIZE];
+    party_pokemon[..PokemonData::SIZE].copy_from_slice(&test_pokemon().encrypt());
+    let mut bytes = party_save(&[party_pokemon]);
+    let game = Game::new_bytes(&mut bytes).unwrap();
+
+    game.export_party_pokemon(0, &path).unwrap();
+    let pkm = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(party_pokemon[..PokemonData::SIZE], pkm);
+    assert!(game.export_party_pokemon(1, &path).is_err());
+
+    let mut pkm: [u8; PokemonData::SIZE] = pkm.try_into().unwrap();
+    let pokemon = DecryptedPokemon::from_pkm_bytes(&pkm).unwrap();
+    assert_eq!(test_pokemon().moves(), pokemon.moves());
+    assert_eq!(pkm, pokemon.to_pkm_bytes());
+    pkm[PokemonData::CHECKSUM_OFFSET] ^= 0xFF;
+    assert!(matches!(
+        DecryptedPokemon::from_pkm_bytes(&pkm),
+        Err(PkError::InvalidData("pkm checksum"))
+    ));
+}
+
+#[test]
+fn pkm_import() {
+    let path = std::env::temp_dir().join(format!("pokedit-import-{}.pkm", std::process::id()));
+    // The previous occupant's party stats must not be kept.
+    let mut previous = [0xAA; PartyPokemonData::SIZE];
+    previous[..PokemonData::SIZE].copy_from_slice(&test_pokemon().encrypt());
+    let mut bytes = party_save(&[previous]);
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+
+    let mut bulbasaur = test_pokemon();
+    bulbasaur.set_species(u16::from(Species::Bulbasaur));
+    // Bulbasaur reaches level 5 at 135 experience points.
+    bulbasaur.set_experience(140);
+    let pkm = bulbasaur.to_pkm_bytes();
+    std::fs::write(&path, pkm).unwrap();
+    game.import_party_pokemon(0, &path).unwrap();
+    game.import_box_pokemon(13, 29, &path).unwrap();
+    assert!(game.import_party_pokemon(1, &path).is_err());
+    assert!(game.import_box_pokemon(14, 0, &path).is_err());
+
+    std::fs::write(&path, &pkm[..PokemonData::SIZE - 1]).unwrap();
+    assert!(matches!(
+        game.import_party_pokemon(0, &path),
+        Err(PkError::InvalidData("pkm size"))
+    ));
+    let mut corrupted = pkm;
+    corrupted[PokemonData::CHECKSUM_OFFSET] ^= 0xFF;
+    std::fs::write(&path, corrupted).unwrap();
+    assert!(matches!(
+        game.import_box_pokemon(0, 0, &path),
+        Err(PkError::InvalidData("pkm checksum"))
+    ));
+    std::fs::remove_file(&path).unwrap();
+
+    let party_pokemon = game.party_pokemon(0).unwrap();
+    assert_eq!(pkm[..], party_pokemon.data[..PokemonData::SIZE]);
+    assert_eq!(5, party_pokemon.level());
+    // Base HP 45 with no IVs or EVs at level 5.
+    assert_eq!(19, party_pokemon.max_hp());
+    assert_eq!(19, party_pokemon.current_hp());
+    assert_eq!(StatusCondition::default(), party_pokemon.status());
+    let stats = bulbasaur.calculate_stats(&Species::Bulbasaur.base_stats().unwrap());
+    for (i, stat) in stats.into_iter().enumerate() {
+        assert_eq!(
+            stat,
+            mem::read_half_word(party_pokemon.data, PartyPokemonData::MAX_HP_OFFSET + i * 2)
+        );
+    }
+    assert_eq!(pkm, game.pc_storage().unwrap().pokemon_raw(13, 29).unwrap());
+    assert_eq!(
+        [0; PokemonData::SIZE],
+        game.pc_storage().unwrap().pokemon_raw(0, 0).unwrap()
+    );
+    for section in game.save_slot().sections() {
+        assert_eq!(Some(section.checksum()), section.calculate_checksum());
+    }
+}
+
+#[test]
+fn owned_game() {
+    let path = std::env::temp_dir().join(format!("pokedit-owned-{}.sav", std::process::id()));
+    let mut game: GameOwned = Game::new_vec(new_save(2).build()).unwrap();
+    game.team_items_mut().set_money(4321).unwrap();
+    game.save(&path).unwrap();
+
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let game = Game::new_vec_with_validation(bytes, Validate::Full).unwrap();
+    assert_eq!(GameVersion::Emerald, game.version());
+    assert_eq!(4321, game.team_items().money());
+
+    let mut corrupted = new_save(2).build();
+    corrupted[0] ^= 0xFF;
+    assert!(Game::new_vec_with_validation(corrupted.clone(), Validate::Full).is_err());
+    assert!(Game::new_vec_with_validation(corrupted, Validate::None).is_ok());
+}
+
+#[test]
+fn rival_name() {
+    let mut bytes = new_save(1).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    game.set_rival_name("Gary").unwrap();
+    assert_eq!("Gary", game.rival_name().unwrap());
+    assert_eq!(
+        [0xC1, 0xD5, 0xE6, 0xED, 0xFF, 0xFF, 0xFF],
+        game.rival_name_raw().unwrap()
+    );
+    assert!(matches!(
+        game.set_rival_name("Blue Oak"),
+        Err(PkError::InvalidData("rival name length"))
+    ));
+
+    // The rival name is stored in section 4.
+    let offset = 0x3A4C;
+    let section = game
+        .save_slot()
+        .sections()
+        .nth(1 + offset / Section::DATA_SIZE)
+        .unwrap();
+    assert_eq!(0xC1, section.data[offset % Section::DATA_SIZE]);
+
+    game.set_rival_name("Gary Oa").unwrap();
+    assert_eq!("Gary Oa", game.rival_name().unwrap());
+    let section = game
+        .save_slot()
+        .sections()
+        .nth(1 + offset / Section::DATA_SIZE)
+        .unwrap();
+    assert_eq!(
+        text::TERMINATOR,
+        section.data[offset % Section::DATA_SIZE + TrainerSection::PLAYER_NAME_LENGTH]
+    );
+
+    let mut bytes = new_save(2).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    assert!(matches!(
+        game.rival_name(),
+        Err(PkError::NotAvailableInGameVersion("rival name"))
+    ));
+    assert!(game.set_rival_name("May").is_err());
+}
+
+#[test]
+fn emerald_vars_offset() {
+    let mut bytes = new_save(2).build();
+    let mut game = Game::new_bytes(&mut bytes).unwrap();
+    game.set_var(0x4021, 0xBEEF).unwrap();
+
+    // The offset is absolute, so it doesn't depend on vars_offset being right.
+    let offset = 0x139C + 0x21 * 2;
+    let section = game
+        .save_slot()
+        .sections()
+        .nth(1 + offset / Section::DATA_SIZE)
+        .unwrap();
+    let offset = offset % Section::DATA_SIZE;
+    assert_eq!([0xEF, 0xBE], section.data[offset..(offset + 2)]);
+}
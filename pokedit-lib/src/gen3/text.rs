@@ -0,0 +1,152 @@
+//! Text encoding used by the Western releases of the games, covering the English, French,
+//! German, Italian and Spanish characters.
+
+use crate::{error::PkError, PkResult};
+
+/// Marks the end of a string shorter than its field.
+pub const TERMINATOR: u8 = 0xFF;
+
+/// Decodes a string, stopping at the first terminator. Characters without a Unicode equivalent
+/// are replaced by `?`.
+pub fn decode(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .take_while(|&&byte| byte != TERMINATOR)
+        .map(|&byte| decode_char(byte).unwrap_or('?'))
+        .collect()
+}
+
+/// Decodes a string like [`decode`], but fails on characters without a Unicode equivalent
+/// instead of replacing them.
+pub fn try_decode(bytes: &[u8]) -> Option<String> {
+    bytes
+        .iter()
+        .take_while(|&&byte| byte != TERMINATOR)
+        .map(|&byte| decode_char(byte))
+        .collect()
+}
+
+/// Returns whether the bytes hold a well formed string: a terminator within the first `max_len`
+/// bytes, with every byte before it being a known character.
+pub fn is_valid(bytes: &[u8], max_len: usize) -> bool {
+    bytes
+        .iter()
+        .take(max_len)
+        .position(|&byte| byte == TERMINATOR)
+        .is_some_and(|len| bytes[..len].iter().all(|&byte| decode_char(byte).is_some()))
+}
+
+/// Encodes a string, without a terminator. Fails if a character can't be represented.
+pub fn encode(s: &str) -> PkResult<Vec<u8>> {
+    s.chars()
+        .map(|c| encode_char(c).ok_or(PkError::InvalidData("text character")))
+        .collect()
+}
+
+/// Encodes a string into exactly `max_len` bytes, followed by a terminator and padded with
+/// terminators, like the fixed size fields of trainer names and nicknames. Fails if the string
+/// doesn't leave room for at least one terminator.
+pub fn encode_padded(s: &str, max_len: usize) -> PkResult<Vec<u8>> {
+    let mut bytes = encode(s)?;
+    if bytes.len() >= max_len {
+        return Err(PkError::InvalidData("text length"));
+    }
+    bytes.resize(max_len, TERMINATOR);
+    Ok(bytes)
+}
+
+fn encode_char(c: char) -> Option<u8> {
+    (0..TERMINATOR).find(|&byte| decode_char(byte) == Some(c))
+}
+
+fn decode_char(byte: u8) -> Option<char> {
+    Some(match byte {
+        0x00 => ' ',
+        0x01 => 'À',
+        0x02 => 'Á',
+        0x03 => 'Â',
+        0x04 => 'Ç',
+        0x05 => 'È',
+        0x06 => 'É',
+        0x07 => 'Ê',
+        0x08 => 'Ë',
+        0x09 => 'Ì',
+        0x0B => 'Î',
+        0x0C => 'Ï',
+        0x0D => 'Ò',
+        0x0E => 'Ó',
+        0x0F => 'Ô',
+        0x10 => 'Œ',
+        0x11 => 'Ù',
+        0x12 => 'Ú',
+        0x13 => 'Û',
+        0x14 => 'Ñ',
+        0x15 => 'ß',
+        0x16 => 'à',
+        0x17 => 'á',
+        0x19 => 'ç',
+        0x1A => 'è',
+        0x1B => 'é',
+        0x1C => 'ê',
+        0x1D => 'ë',
+        0x1E => 'ì',
+        0x20 => 'î',
+        0x21 => 'ï',
+        0x22 => 'ò',
+        0x23 => 'ó',
+        0x24 => 'ô',
+        0x25 => 'œ',
+        0x26 => 'ù',
+        0x27 => 'ú',
+        0x28 => 'û',
+        0x29 => 'ñ',
+        0x2A => 'º',
+        0x2B => 'ª',
+        0x2D => '&',
+        0x2E => '+',
+        0x35 => '=',
+        0x36 => ';',
+        0x51 => '¿',
+        0x52 => '¡',
+        0x5A => 'Í',
+        0x5B => '%',
+        0x5C => '(',
+        0x5D => ')',
+        0x68 => 'â',
+        0x6F => 'í',
+        0x79 => '↑',
+        0x7A => '↓',
+        0x7B => '←',
+        0x7C => '→',
+        0x85 => '<',
+        0x86 => '>',
+        0xA1..=0xAA => char::from(b'0' + (byte - 0xA1)),
+        0xAB => '!',
+        0xAC => '?',
+        0xAD => '.',
+        0xAE => '-',
+        0xAF => '·',
+        0xB0 => '…',
+        0xB1 => '“',
+        0xB2 => '”',
+        0xB3 => '‘',
+        0xB4 => '’',
+        0xB5 => '♂',
+        0xB6 => '♀',
+        0xB7 => '$',
+        0xB8 => ',',
+        0xB9 => '×',
+        0xBA => '/',
+        0xBB..=0xD4 => char::from(b'A' + (byte - 0xBB)),
+        0xD5..=0xEE => char::from(b'a' + (byte - 0xD5)),
+        0xEF => '►',
+        0xF0 => ':',
+        0xF1 => 'Ä',
+        0xF2 => 'Ö',
+        0xF3 => 'Ü',
+        0xF4 => 'ä',
+        0xF5 => 'ö',
+        0xF6 => 'ü',
+        _ => return None,
+    })
+}
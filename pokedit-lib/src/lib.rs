@@ -3,6 +3,6 @@ pub mod error;
 pub mod gen3;
 mod mem;
 
-pub use error::PkError;
+pub use error::{PkError, PkResultExt};
 
 pub type PkResult<T> = Result<T, PkError>;
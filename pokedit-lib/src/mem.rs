@@ -2,6 +2,8 @@ pub mod le {
     #![allow(dead_code)]
     use std::io::Write;
 
+    use crate::{PkError, PkResult};
+
     /// Writes `value` as little endian into `bytes` at `offset`.
     ///
     /// ```text
@@ -28,6 +30,70 @@ pub mod le {
         u32::from_le_bytes(bytes[offset..(offset + 4)].try_into().unwrap())
     }
 
+    /// Copies `src` into `bytes` at `offset`.
+    ///
+    /// ```text
+    /// bytes[offset..(offset + src.len())] = src;
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `src` doesn't fit in `bytes` at `offset`, see [`copy_bytes_checked`].
+    pub fn copy_bytes(bytes: &mut [u8], offset: usize, src: &[u8]) {
+        bytes[offset..(offset + src.len())].copy_from_slice(src);
+    }
+
+    /// Copies `src` into `bytes` at `offset`, like [`copy_bytes`], but fails instead of panicking
+    /// if it doesn't fit.
+    pub fn copy_bytes_checked(bytes: &mut [u8], offset: usize, src: &[u8]) -> PkResult<()> {
+        match offset.checked_add(src.len()) {
+            Some(end) if end <= bytes.len() => {
+                copy_bytes(bytes, offset, src);
+                Ok(())
+            }
+            end => Err(PkError::IndexOutOfBounds {
+                name: "byte",
+                index: end.map_or(usize::MAX, |end| end - 1),
+                len: bytes.len(),
+            }),
+        }
+    }
+
+    /// Reads `bit_count` bits starting at `bit_offset` of the little endian word at `byte_offset`.
+    ///
+    /// ```text
+    /// (read_word(bytes, byte_offset) >> bit_offset) & ((1 << bit_count) - 1)
+    /// ```
+    pub fn read_bits(bytes: &[u8], byte_offset: usize, bit_offset: u8, bit_count: u8) -> u32 {
+        (read_word(bytes, byte_offset) & bit_mask(bit_offset, bit_count)) >> bit_offset
+    }
+
+    /// Writes the lowest `bit_count` bits of `value` starting at `bit_offset` of the little endian
+    /// word at `byte_offset`, leaving the other bits as is.
+    pub fn write_bits(
+        bytes: &mut [u8],
+        byte_offset: usize,
+        bit_offset: u8,
+        bit_count: u8,
+        value: u32,
+    ) {
+        let mask = bit_mask(bit_offset, bit_count);
+        let word = read_word(bytes, byte_offset);
+        write_word(
+            bytes,
+            byte_offset,
+            (word & !mask) | ((value << bit_offset) & mask),
+        );
+    }
+
+    fn bit_mask(bit_offset: u8, bit_count: u8) -> u32 {
+        debug_assert!(
+            bit_count > 0 && u32::from(bit_offset) + u32::from(bit_count) <= 32,
+            "bit field {bit_offset}+{bit_count} doesn't fit in a word"
+        );
+        (u32::MAX >> (32 - bit_count)) << bit_offset
+    }
+
     #[cfg(test)]
     mod tests {
         #[test]
@@ -39,8 +105,8 @@ pub mod le {
 
         #[test]
         fn read_half_word() {
-            let mut bytes = [0, 0x34, 0x12, 0];
-            assert_eq!(0x1234, super::read_half_word(&mut bytes, 1));
+            let bytes = [0, 0x34, 0x12, 0];
+            assert_eq!(0x1234, super::read_half_word(&bytes, 1));
         }
 
         #[test]
@@ -52,8 +118,38 @@ pub mod le {
 
         #[test]
         fn read_word() {
-            let mut bytes = [0, 0x78, 0x56, 0x34, 0x12, 0];
-            assert_eq!(0x12345678, super::read_word(&mut bytes, 1));
+            let bytes = [0, 0x78, 0x56, 0x34, 0x12, 0];
+            assert_eq!(0x12345678, super::read_word(&bytes, 1));
+        }
+
+        #[test]
+        fn copy_bytes() {
+            let mut bytes = [0u8; 4];
+            super::copy_bytes(&mut bytes, 1, &[1, 2, 3]);
+            assert_eq!([0, 1, 2, 3], bytes);
+            assert!(super::copy_bytes_checked(&mut bytes, 2, &[4, 5]).is_ok());
+            assert_eq!([0, 1, 4, 5], bytes);
+            assert!(super::copy_bytes_checked(&mut bytes, 3, &[6, 7]).is_err());
+            assert!(super::copy_bytes_checked(&mut bytes, usize::MAX, &[6]).is_err());
+            assert_eq!([0, 1, 4, 5], bytes);
+        }
+
+        #[test]
+        fn read_bits() {
+            let bytes = [0, 0b1010_0110, 0, 0, 0x80, 0];
+            assert_eq!(0b10011, super::read_bits(&bytes, 1, 1, 5));
+            assert_eq!(1, super::read_bits(&bytes, 1, 31, 1));
+            assert_eq!(0x8000_00A6, super::read_bits(&bytes, 1, 0, 32));
+        }
+
+        #[test]
+        fn write_bits() {
+            let mut bytes = [0xFFu8; 6];
+            super::write_bits(&mut bytes, 1, 5, 5, 0b1_0100);
+            assert_eq!([0xFF, 0x9F, 0xFE, 0xFF, 0xFF, 0xFF], bytes);
+            // Bits outside of the field are ignored.
+            super::write_bits(&mut bytes, 1, 30, 2, 0xFFFF_FFF0);
+            assert_eq!([0xFF, 0x9F, 0xFE, 0xFF, 0x3F, 0xFF], bytes);
         }
     }
 }
@@ -83,8 +179,8 @@ pub mod be {
 
         #[test]
         fn read_half_word() {
-            let mut bytes = [0, 0x12, 0x34, 0];
-            assert_eq!(0x1234, super::read_half_word(&mut bytes, 1));
+            let bytes = [0, 0x12, 0x34, 0];
+            assert_eq!(0x1234, super::read_half_word(&bytes, 1));
         }
     }
 }
@@ -3,13 +3,16 @@ use std::path::{Path, PathBuf};
 use embedded_graphics::{
     draw_target::DrawTarget,
     geometry::Point,
-    mono_font::{iso_8859_14::FONT_10X20, MonoTextStyle},
+    mono_font::{
+        iso_8859_14::{FONT_10X20, FONT_6X10},
+        MonoFont, MonoTextStyle,
+    },
     pixelcolor::{Rgb888, RgbColor},
     text::Text,
     Drawable,
 };
 use log::info;
-use pokedit_lib::gen3::Game;
+use pokedit_lib::gen3::{Game, GameOwned, TeamItemsSection};
 
 use crate::app::input::{Key, KeyEvent};
 
@@ -18,6 +21,9 @@ pub mod input;
 pub trait Platform: DrawTarget {
     fn display_width(&self) -> u32;
     fn display_height(&self) -> u32;
+    fn dimensions(&self) -> (u32, u32) {
+        (self.display_width(), self.display_height())
+    }
     fn flush(&mut self);
     async fn poll(&mut self) -> input::KeyEvent;
 }
@@ -25,7 +31,11 @@ pub trait Platform: DrawTarget {
 #[derive(Debug, Default)]
 pub struct AppState {
     save_file: PathBuf,
-    game: Option<Game<'static>>,
+    game: Option<GameOwned>,
+    /// Whether the game has unsaved changes.
+    dirty: bool,
+    /// Chosen on the first draw, see [`App::choose_font`].
+    font: Option<MonoFont<'static>>,
 }
 
 #[derive(Debug)]
@@ -46,14 +56,36 @@ impl<P> App<P> {
         let path = path.as_ref();
         self.state.save_file = path.into();
         let file = std::fs::read(path)?;
-        self.state.game = Some(pokedit_lib::gen3::Game::new_vec(file)?);
+        self.state.game = Some(Game::new_vec(file)?);
+        self.state.dirty = false;
         Ok(())
     }
 
+    /// Sets the game to manage without reading it from a file, e.g. in tests. The game is saved to
+    /// `display_name` when quitting.
+    // The simulator always opens a file.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn set_game(&mut self, game: GameOwned, display_name: String) {
+        self.state.game = Some(game);
+        self.state.save_file = display_name.into();
+        self.state.dirty = true;
+    }
+
+    /// Removes the game from the app, e.g. to inspect it after simulating some input. Its unsaved
+    /// changes are discarded from the app, so nothing is saved when quitting.
+    // The simulator saves the game when quitting instead.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn take_game(&mut self) -> Option<GameOwned> {
+        self.state.dirty = false;
+        self.state.game.take()
+    }
+
     fn quit(&mut self) -> anyhow::Result<()> {
         if let Some(game) = &mut self.state.game {
-            info!("Saving game");
-            game.save(&self.state.save_file)?;
+            if self.state.dirty {
+                info!("Saving game");
+                game.save(&self.state.save_file)?;
+            }
         }
         Ok(())
     }
@@ -77,14 +109,18 @@ where
                     if let Some(game) = &mut self.state.game {
                         info!("Increasing money!");
                         let money = game.team_items().money();
-                        game.team_items_mut().set_money(money.saturating_add(1));
+                        game.team_items_mut()
+                            .set_money(money.saturating_add(1).min(TeamItemsSection::MAX_MONEY))?;
+                        self.state.dirty = true;
                     }
                 }
                 KeyEvent::Pressed(Key::Down) | KeyEvent::Autorepeat(Key::Down) => {
                     if let Some(game) = &mut self.state.game {
                         info!("Increasing money!");
                         let money = game.team_items().money();
-                        game.team_items_mut().set_money(money.saturating_sub(1));
+                        game.team_items_mut()
+                            .set_money(money.saturating_sub(1).min(TeamItemsSection::MAX_MONEY))?;
+                        self.state.dirty = true;
                     }
                 }
                 _ => {}
@@ -94,17 +130,36 @@ where
         self.quit()
     }
 
+    /// Picks a font readable at the given display size. 10x20 is the largest font available, so
+    /// it is also used for displays wider than 640px.
+    pub fn choose_font(width: u32, _height: u32) -> MonoFont<'static> {
+        match width {
+            0..320 => FONT_6X10,
+            _ => FONT_10X20,
+        }
+    }
+
+    /// Returns the point at the center of the display.
+    pub fn center_point(&self) -> Point {
+        let (width, height) = self.platform.dimensions();
+        Point::new(width as i32 / 2, height as i32 / 2)
+    }
+
     fn draw(&mut self) -> anyhow::Result<()> {
-        let width = self.platform.display_width() as i32;
-        let height = self.platform.display_height() as i32;
+        let center = self.center_point();
+        let (width, height) = self.platform.dimensions();
+        let font = *self
+            .state
+            .font
+            .get_or_insert_with(|| Self::choose_font(width, height));
         self.platform.clear(Rgb888::WHITE)?;
 
         if let Some(game) = &self.state.game {
             let money = game.team_items().money().to_string();
             let text = Text::with_alignment(
                 &money,
-                Point::new(width / 2, height / 2),
-                MonoTextStyle::new(&FONT_10X20, Rgb888::BLACK),
+                center,
+                MonoTextStyle::new(&font, Rgb888::BLACK),
                 embedded_graphics::text::Alignment::Center,
             );
             text.draw(&mut self.platform)?;
@@ -114,3 +169,101 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::VecDeque, convert::Infallible};
+
+    use embedded_graphics::{geometry::OriginDimensions, prelude::Size, Pixel};
+    use pokedit_lib::gen3::{DataView, SaveSlot, Section};
+
+    use super::*;
+
+    struct TestPlatform {
+        events: VecDeque<KeyEvent>,
+    }
+
+    impl Platform for TestPlatform {
+        fn display_width(&self) -> u32 {
+            320
+        }
+
+        fn display_height(&self) -> u32 {
+            240
+        }
+
+        fn flush(&mut self) {}
+
+        async fn poll(&mut self) -> KeyEvent {
+            self.events
+                .pop_front()
+                .unwrap_or(KeyEvent::Pressed(Key::Quit))
+        }
+    }
+
+    impl OriginDimensions for TestPlatform {
+        fn size(&self) -> Size {
+            Size::new(self.display_width(), self.display_height())
+        }
+    }
+
+    impl DrawTarget for TestPlatform {
+        type Color = Rgb888;
+
+        type Error = Infallible;
+
+        fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            Ok(())
+        }
+    }
+
+    /// Returns a Ruby/Sapphire save with both slots valid and zeroed, which also zeroes their
+    /// checksums.
+    fn blank_save() -> GameOwned {
+        let mut bytes = vec![0; 128 * 1024];
+        for (slot, slot_bytes) in bytes.chunks_exact_mut(SaveSlot::SIZE).take(2).enumerate() {
+            for (id, section) in slot_bytes.chunks_exact_mut(Section::SIZE).enumerate() {
+                section[Section::SECTION_ID_OFFSET..][..2]
+                    .copy_from_slice(&(id as u16).to_le_bytes());
+                section[Section::SIGNATURE_OFFSET..][..4]
+                    .copy_from_slice(&Section::MAGIC_SIGNATURE.to_le_bytes());
+                section[Section::SAVE_INDEX_OFFSET..][..4]
+                    .copy_from_slice(&(slot as u32 + 1).to_le_bytes());
+            }
+        }
+        Game::new_vec(bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn set_game_is_saved_on_quit() {
+        let path = std::env::temp_dir().join(format!("pokedit-app-{}.sav", std::process::id()));
+        let mut app = App::new(TestPlatform {
+            events: VecDeque::from([KeyEvent::Pressed(Key::Up), KeyEvent::Autorepeat(Key::Up)]),
+        });
+        app.set_game(blank_save(), path.to_string_lossy().into_owned());
+        app.run_event_loop().await.unwrap();
+
+        let saved = Game::new_vec(std::fs::read(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(2, saved.team_items().money());
+    }
+
+    #[tokio::test]
+    async fn take_game_isnt_saved_on_quit() {
+        let path =
+            std::env::temp_dir().join(format!("pokedit-app-take-{}.sav", std::process::id()));
+        let mut app = App::new(TestPlatform {
+            events: VecDeque::from([KeyEvent::Pressed(Key::Up)]),
+        });
+        app.set_game(blank_save(), path.to_string_lossy().into_owned());
+        let game = app.take_game().unwrap();
+        assert!(app.take_game().is_none());
+        app.run_event_loop().await.unwrap();
+
+        assert!(!path.exists());
+        assert_eq!(0, game.team_items().money());
+    }
+}
@@ -1,12 +1,40 @@
-use std::path::PathBuf;
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::PathBuf,
+};
 
-use anyhow::Result;
-use pokedit::{parse_args, BinaryConfig};
+use anyhow::{anyhow, Result};
+use pokedit::{parse_args, BinaryConfig, SectionDump, Subcommand};
+use pokedit_lib::gen3::{
+    moves, species, Data, Game, Gender, SaveChange, SaveSlot, Section, SectionValidationIssue,
+    TeamItemsSection, Validate,
+};
 
 const HELP_STR: &str = "
 A pokemon save file editor
 
-Usage: pokedit [OPTIONS] FILE
+Usage: pokedit [OPTIONS] [COMMAND] FILE [COMMAND ARGS]
+
+Options:
+    --json\t\t\t\tPrint the output as JSON, requires the serde feature.
+    --output FILE\t\t\tWrite the output to FILE instead of stdout, or the edited save.
+    --set-money VALUE\t\tSet the player's money.
+    --set-name NAME\t\tSet the player's name.
+    --in-place\t\t\tAllow overwriting FILE when editing the save.
+    --validate\t\t\tReport the integrity of the save. Exits with 2 if a checksum is wrong
+    \t\t\t\tand 1 on any other issue.
+    --dump-section ID\t\tWrite the raw bytes of the section ID of the current save slot.
+    --dump-section all\t\tWrite every section to section_N.bin files, in the --output
+    \t\t\t\tdirectory if given.
+
+Commands:
+    info\t\t\t\tPrint a summary of the save (default).
+    party\t\t\t\tPrint the Pokemon in the party.
+    export-pokemon SLOT OUTPUT\tExport a party Pokemon as a .pk3 file.
+    import-pokemon SLOT INPUT\tOverwrite a party Pokemon with a .pk3 file, requires --output
+    \t\t\t\tor --in-place.
+    compare OTHER\t\t\tPrint what changed from FILE to OTHER.
 
 Arguments:
     FILE\tSave file to edit.
@@ -17,25 +45,523 @@ fn main() -> Result<()> {
 
     let args = parse_args(BinaryConfig {
         help: HELP_STR.trim(),
+        commands: &[
+            "info",
+            "party",
+            "export-pokemon",
+            "import-pokemon",
+            "compare",
+        ],
     });
+    if (args.set_money.is_some() || args.set_name.is_some()) && args.subcommand.is_some() {
+        return Err(anyhow!(
+            "--set-money and --set-name can't be combined with a command"
+        ));
+    }
     let save_file_path = args.input.unwrap_or_else(|| {
         PathBuf::from("./savs/Pokemon - Emerald Version (USA, Europe).sav".to_string())
     });
-    let mut bytes = std::fs::read(save_file_path)?;
-    let game = pokedit_lib::gen3::Game::new_bytes(&mut bytes)?;
-    println!("Gender: {}", game.trainer().gender()?);
-    println!("Public TrainerId: {}", game.trainer().trainer_id().public);
-    println!("Private TrainerId: {}", game.trainer().trainer_id().private);
-    println!("Time played: {:?}", game.trainer().time_played());
-    println!("Security code: 0x{:08X}", game.trainer().security_key()?);
-    println!("Money: {}", game.team_items().money());
-    //println!("Setting Money");
-    //{
-    //    let mut team_items = game.team_items_mut();
-    //    let money = team_items.as_data().money() + 1;
-    //    team_items.set_money(money);
-    //}
-    //println!("Money: {}", game.team_items().money());
-    //game.save(save_file_path)?;
+    let mut bytes = std::fs::read(&save_file_path)?;
+    if args.validate {
+        std::process::exit(print_validation_report(bytes));
+    }
+    if let Some(dump) = args.dump_section {
+        // Sections are dumped as is, so corrupt saves can be inspected too.
+        let game = Game::new_with_validation(&mut bytes, Validate::None)?;
+        return dump_sections(&game, dump, args.output);
+    }
+    let mut game = Game::new_bytes(&mut bytes)?;
+    if args.set_money.is_some() || args.set_name.is_some() {
+        let output = edited_save_path(args.output, args.in_place, save_file_path)?;
+        if let Some(money) = args.set_money {
+            let old_money = game.team_items().money();
+            game.team_items_mut().set_money(money)?;
+            println!("money: {old_money} -> {money}");
+        }
+        if let Some(name) = &args.set_name {
+            // An undecodable name is still overwritten.
+            let old_name = game.trainer().name().unwrap_or_else(|_| "?".to_string());
+            game.trainer_mut().set_name(name)?;
+            println!("name: {old_name} -> {name}");
+        }
+        game.save(output)?;
+        return Ok(());
+    }
+
+    match args.subcommand {
+        Some(Subcommand::Party) => print_party(&game, &mut output(args.output)?)?,
+        Some(Subcommand::ExportPokemon { slot, output }) => {
+            let party_pokemon = game
+                .team_items()
+                .party()
+                .nth(slot)
+                .ok_or_else(|| anyhow!("there is no Pokemon in party slot {slot}"))?;
+            std::fs::write(output, party_pokemon.to_pk3())?;
+        }
+        Some(Subcommand::ImportPokemon { slot, input }) => {
+            let pk3 = std::fs::read(input)?;
+            game.team_items_mut()
+                .party_mut()
+                .nth(slot)
+                .ok_or_else(|| anyhow!("there is no Pokemon in party slot {slot}"))?
+                .set_from_pk3(&pk3)?;
+            game.save(edited_save_path(
+                args.output,
+                args.in_place,
+                save_file_path,
+            )?)?;
+        }
+        Some(Subcommand::Compare { other }) => {
+            let mut other_bytes = std::fs::read(other)?;
+            let other = Game::new_bytes(&mut other_bytes)?;
+            print_changes(&game.diff(&other)?, args.json, &mut output(args.output)?)?;
+        }
+        Some(Subcommand::Info) | None => print_info(&game, args.json, &mut output(args.output)?)?,
+    }
+
+    Ok(())
+}
+
+/// Returns where the output should be written, `path` if given or stdout otherwise.
+fn output(path: Option<PathBuf>) -> Result<Box<dyn Write>> {
+    Ok(match path {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout()),
+    })
+}
+
+/// Returns where an edited save should be written, refusing to overwrite the input save unless
+/// `in_place` is set.
+fn edited_save_path(output: Option<PathBuf>, in_place: bool, input: PathBuf) -> Result<PathBuf> {
+    match (output, in_place) {
+        (Some(output), _) => Ok(output),
+        (None, true) => Ok(input),
+        (None, false) => Err(anyhow!(
+            "editing the save requires --output FILE, or --in-place to overwrite it"
+        )),
+    }
+}
+
+fn print_party(game: &Game, out: &mut dyn Write) -> Result<()> {
+    writeln!(
+        out,
+        "| # | {:<10} | Level | {:<7} | {:<56} |",
+        "Species", "HP", "Moves"
+    )?;
+    let mut party = game.team_items().party();
+    for slot in 1..=TeamItemsSection::PARTY_SIZE {
+        let Some(party_pokemon) = party.next() else {
+            writeln!(
+                out,
+                "| {slot} | {:<10} | {:<5} | {:<7} | {:<56} |",
+                "---", "", "", ""
+            )?;
+            continue;
+        };
+
+        let pokemon = party_pokemon.pokemon().decrypt();
+        let moves = pokemon
+            .moves()
+            .into_iter()
+            .filter(|&move_id| move_id != 0)
+            .map(|move_id| moves::name(move_id).unwrap_or("?"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            out,
+            "| {slot} | {:<10} | {:<5} | {:<7} | {:<56} |",
+            if pokemon.is_egg() {
+                "Egg"
+            } else {
+                species::name(pokemon.species()).unwrap_or("?")
+            },
+            party_pokemon.level(),
+            format!("{}/{}", party_pokemon.current_hp(), party_pokemon.max_hp()),
+            moves,
+        )?;
+    }
+    Ok(())
+}
+
+/// The summary of the save printed by `info`.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct Info {
+    version: String,
+    name: String,
+    gender: Gender,
+    public_id: u16,
+    private_id: u16,
+    time_played: String,
+    money: u32,
+    team_size: usize,
+    badges: u8,
+    pokedex_owned: u16,
+    pokedex_seen: u16,
+    party: Vec<PartyMember>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct PartyMember {
+    species: &'static str,
+    level: u8,
+}
+
+fn print_info(game: &Game, json: bool, out: &mut dyn Write) -> Result<()> {
+    let trainer = game.trainer();
+    let team_items = game.team_items();
+    let trainer_id = trainer.trainer_id();
+    let time_played = trainer.time_played();
+    let info = Info {
+        version: game.version().to_string(),
+        name: trainer.name()?,
+        gender: trainer.gender()?,
+        public_id: trainer_id.public,
+        private_id: trainer_id.private,
+        time_played: format!(
+            "{}:{:02}:{:02}",
+            time_played.hours, time_played.minutes, time_played.seconds
+        ),
+        money: team_items.money(),
+        team_size: team_items.team_size(),
+        badges: game.badges_earned_count()?,
+        pokedex_owned: trainer.pokedex_owned_count(),
+        pokedex_seen: trainer.pokedex_seen_count(),
+        party: team_items
+            .party()
+            .map(|party_pokemon| {
+                let pokemon = party_pokemon.pokemon().decrypt();
+                let species = if pokemon.is_egg() {
+                    "Egg"
+                } else {
+                    species::name(pokemon.species()).unwrap_or("?")
+                };
+                PartyMember {
+                    species,
+                    level: party_pokemon.level(),
+                }
+            })
+            .collect(),
+    };
+
+    if json {
+        return json::write_versioned(&info, out);
+    }
+
+    writeln!(out, "Version: {}", info.version)?;
+    writeln!(out, "Name: {}", info.name)?;
+    writeln!(out, "Gender: {}", info.gender)?;
+    writeln!(out, "Public TrainerId: {}", info.public_id)?;
+    writeln!(out, "Private TrainerId: {}", info.private_id)?;
+    writeln!(out, "Time played: {}", info.time_played)?;
+    writeln!(out, "Money: {}", info.money)?;
+    writeln!(out, "Team size: {}", info.team_size)?;
+    writeln!(out, "Badges: {}", info.badges)?;
+    writeln!(out, "Pokedex owned: {}", info.pokedex_owned)?;
+    writeln!(out, "Pokedex seen: {}", info.pokedex_seen)?;
+    for (slot, member) in info.party.iter().enumerate() {
+        writeln!(
+            out,
+            "Party {}: {} Lv. {}",
+            slot + 1,
+            member.species,
+            member.level
+        )?;
+    }
     Ok(())
 }
+
+/// Prints the integrity of both save slots, returning the exit code: 0 if the save is valid, 2 if
+/// any checksum is wrong and 1 on any other issue.
+fn print_validation_report(mut bytes: Vec<u8>) -> i32 {
+    let full_validation = Game::new_with_validation(&mut bytes.clone(), Validate::Full).map(|_| ());
+    let game = match Game::new_with_validation(&mut bytes, Validate::None) {
+        Ok(game) => game,
+        Err(e) => {
+            println!("The save could not be loaded: {e}");
+            return 1;
+        }
+    };
+
+    let mut wrong_checksum = false;
+    let slots = [
+        ("Current", game.current_save_slot_id(), game.save_slot()),
+        (
+            "Backup",
+            game.backup_save_slot_id(),
+            game.backup_save_slot(),
+        ),
+    ];
+    for (kind, id, save_slot) in slots {
+        println!(
+            "{kind} save slot ({id:?}), save index {}:",
+            save_slot.save_index()
+        );
+        if kind == "Backup" && !game.backup_slot_ever_saved() {
+            println!("    never saved");
+            continue;
+        }
+        println!(
+            "    sections found: {}/{}",
+            section_ids_found(save_slot),
+            SaveSlot::SECTION_COUNT
+        );
+        for section in save_slot.sections() {
+            let issues = section.validate_with_report();
+            let checksum = match issues.iter().find_map(|issue| match issue {
+                SectionValidationIssue::WrongChecksum { found, expected } => {
+                    Some((found, expected))
+                }
+                _ => None,
+            }) {
+                Some((found, expected)) => {
+                    wrong_checksum = true;
+                    format!("wrong (found 0x{found:04X}, expected 0x{expected:04X})")
+                }
+                None if Section::is_valid_id(section.id()) => "ok".to_string(),
+                None => "unknown".to_string(),
+            };
+            let signature = if section.signature() == Section::MAGIC_SIGNATURE {
+                "ok".to_string()
+            } else {
+                format!("wrong (found 0x{:08X})", section.signature())
+            };
+            println!(
+                "    section {:>2}: checksum {checksum}, signature {signature}",
+                section.id()
+            );
+        }
+    }
+
+    let (current_index, backup_index) = (
+        game.save_slot().save_index(),
+        game.backup_save_slot().save_index(),
+    );
+    let consistent =
+        !game.backup_slot_ever_saved() || backup_index == current_index.wrapping_sub(1);
+    println!(
+        "Save slots consistent: {}",
+        if consistent { "yes" } else { "no" }
+    );
+
+    match full_validation {
+        Ok(_) => {
+            println!("The save is valid.");
+            0
+        }
+        Err(e) => {
+            println!("The save is invalid: {e}");
+            if wrong_checksum {
+                2
+            } else {
+                1
+            }
+        }
+    }
+}
+
+fn dump_sections(game: &Game, dump: SectionDump, output: Option<PathBuf>) -> Result<()> {
+    match dump {
+        SectionDump::Id(id) => {
+            let section = game.section(id)?;
+            match output {
+                Some(path) => std::fs::write(path, section.as_bytes())?,
+                None => io::stdout().write_all(section.as_bytes())?,
+            }
+        }
+        SectionDump::All => {
+            let directory = output.unwrap_or_else(|| PathBuf::from("."));
+            for id in 0..SaveSlot::SECTION_COUNT as u16 {
+                let path = directory.join(format!("section_{id}.bin"));
+                std::fs::write(path, game.section(id)?.as_bytes())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns how many of the section IDs are present in the save slot.
+fn section_ids_found(save_slot: Data<SaveSlot>) -> usize {
+    let mut found = [false; SaveSlot::SECTION_COUNT];
+    for section in save_slot.sections() {
+        if let Some(found) = found.get_mut(usize::from(section.id())) {
+            *found = true;
+        }
+    }
+    found.into_iter().filter(|&found| found).count()
+}
+
+fn print_changes(changes: &[SaveChange], json: bool, out: &mut dyn Write) -> Result<()> {
+    if json {
+        return json::write_changes(changes, out);
+    }
+
+    let species_name = |species| match species {
+        0 => "---",
+        species => species::name(species).unwrap_or("?"),
+    };
+    for change in changes {
+        match change {
+            SaveChange::Money { before, after } => writeln!(out, "money: {before} -> {after}"),
+            SaveChange::Badges { before, after } => writeln!(out, "badges: {before} -> {after}"),
+            SaveChange::TimePlayed { before, after } => writeln!(
+                out,
+                "time played: {}:{:02}:{:02} -> {}:{:02}:{:02}",
+                before.hours,
+                before.minutes,
+                before.seconds,
+                after.hours,
+                after.minutes,
+                after.seconds
+            ),
+            SaveChange::PartyPokemon {
+                slot,
+                before,
+                after,
+            } => writeln!(
+                out,
+                "party {}: {} -> {}",
+                slot + 1,
+                species_name(*before),
+                species_name(*after)
+            ),
+            SaveChange::Item {
+                pocket,
+                id,
+                before,
+                after,
+            } => writeln!(out, "item {pocket} #{id}: {before} -> {after}"),
+        }?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+mod json {
+    use std::io::Write;
+
+    use anyhow::Result;
+    use pokedit_lib::gen3::{PocketKind, SaveChange};
+    use serde::Serialize;
+
+    /// Version of the JSON schema of [`write_versioned`], bumped whenever it changes.
+    const FORMAT_VERSION: u32 = 1;
+
+    #[derive(Debug, Serialize)]
+    struct Versioned<'a, T> {
+        format_version: u32,
+        #[serde(flatten)]
+        data: &'a T,
+    }
+
+    /// The JSON representation of a [`SaveChange`], tagged by the changed field.
+    #[derive(Debug, Serialize)]
+    #[serde(tag = "field", rename_all = "snake_case")]
+    enum Change {
+        Money {
+            before: u32,
+            after: u32,
+        },
+        Badges {
+            before: u8,
+            after: u8,
+        },
+        /// The time played in seconds.
+        TimePlayed {
+            before: u32,
+            after: u32,
+        },
+        Party {
+            slot: usize,
+            before: u16,
+            after: u16,
+        },
+        Item {
+            pocket: PocketKind,
+            id: u16,
+            before: u16,
+            after: u16,
+        },
+    }
+
+    impl From<SaveChange> for Change {
+        fn from(change: SaveChange) -> Self {
+            match change {
+                SaveChange::Money { before, after } => Change::Money { before, after },
+                SaveChange::Badges { before, after } => Change::Badges { before, after },
+                SaveChange::TimePlayed { before, after } => Change::TimePlayed {
+                    before: before.total_seconds(),
+                    after: after.total_seconds(),
+                },
+                SaveChange::PartyPokemon {
+                    slot,
+                    before,
+                    after,
+                } => Change::Party {
+                    slot,
+                    before,
+                    after,
+                },
+                SaveChange::Item {
+                    pocket,
+                    id,
+                    before,
+                    after,
+                } => Change::Item {
+                    pocket,
+                    id,
+                    before,
+                    after,
+                },
+            }
+        }
+    }
+
+    pub fn write(value: &impl Serialize, out: &mut dyn Write) -> Result<()> {
+        serde_json::to_writer(&mut *out, value)?;
+        writeln!(out)?;
+        Ok(())
+    }
+
+    /// Writes the value with a top-level `format_version` key, so that downstream tools can
+    /// detect schema changes.
+    pub fn write_versioned(value: &impl Serialize, out: &mut dyn Write) -> Result<()> {
+        write(
+            &Versioned {
+                format_version: FORMAT_VERSION,
+                data: value,
+            },
+            out,
+        )
+    }
+
+    pub fn write_changes(changes: &[SaveChange], out: &mut dyn Write) -> Result<()> {
+        let changes = changes
+            .iter()
+            .map(|&change| Change::from(change))
+            .collect::<Vec<_>>();
+        write(&changes, out)
+    }
+}
+
+/// JSON output needs serde, so `--json` fails when built without it.
+#[cfg(not(feature = "serde"))]
+mod json {
+    use std::io::Write;
+
+    use anyhow::{anyhow, Result};
+    use pokedit_lib::gen3::SaveChange;
+
+    fn unsupported() -> anyhow::Error {
+        anyhow!("--json requires the serde feature")
+    }
+
+    pub fn write_versioned<T>(_value: &T, _out: &mut dyn Write) -> Result<()> {
+        Err(unsupported())
+    }
+
+    pub fn write_changes(_changes: &[SaveChange], _out: &mut dyn Write) -> Result<()> {
+        Err(unsupported())
+    }
+}
@@ -119,6 +119,7 @@ async fn main() -> anyhow::Result<()> {
 
     let args = parse_args(BinaryConfig {
         help: HELP_STR.trim(),
+        commands: &[],
     });
     let mut app = App::new(SimulatorPlatform::new());
     if let Some(save_file_path) = args.input {
@@ -1,8 +1,10 @@
-use std::path::PathBuf;
+use std::{ffi::OsString, num::ParseIntError, path::PathBuf, str::FromStr};
 
 #[derive(Debug)]
 pub struct BinaryConfig {
     pub help: &'static str,
+    /// Subcommands accepted before the input file.
+    pub commands: &'static [&'static str],
 }
 
 impl BinaryConfig {
@@ -18,21 +20,121 @@ impl BinaryConfig {
 
 #[derive(Debug)]
 pub struct Args {
+    pub subcommand: Option<Subcommand>,
     pub input: Option<PathBuf>,
+    /// Print the output as JSON.
+    pub json: bool,
+    /// Write the output to this file instead of stdout, or the modified save when editing it.
+    pub output: Option<PathBuf>,
+    pub set_money: Option<u32>,
+    pub set_name: Option<String>,
+    /// Allow overwriting the input save when editing it.
+    pub in_place: bool,
+    /// Print a report of the save integrity instead of running a command.
+    pub validate: bool,
+    /// Dump the raw bytes of sections instead of running a command.
+    pub dump_section: Option<SectionDump>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionDump {
+    Id(u16),
+    All,
+}
+
+impl FromStr for SectionDump {
+    type Err = ParseIntError;
+
+    /// Parses `all` or a section ID.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            id => id.parse().map(Self::Id),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Subcommand {
+    Info,
+    Party,
+    ExportPokemon { slot: usize, output: PathBuf },
+    ImportPokemon { slot: usize, input: PathBuf },
+    Compare { other: PathBuf },
+}
+
+impl Subcommand {
+    /// Parses the subcommand from its name and the arguments following the input file.
+    fn parse(name: &str, args: Vec<OsString>) -> Option<Self> {
+        let mut args = args.into_iter();
+        let subcommand = match name {
+            "info" => Self::Info,
+            "party" => Self::Party,
+            "export-pokemon" => Self::ExportPokemon {
+                slot: args.next()?.into_string().ok()?.parse().ok()?,
+                output: args.next()?.into(),
+            },
+            "import-pokemon" => Self::ImportPokemon {
+                slot: args.next()?.into_string().ok()?.parse().ok()?,
+                input: args.next()?.into(),
+            },
+            "compare" => Self::Compare {
+                other: args.next()?.into(),
+            },
+            _ => return None,
+        };
+
+        args.next().is_none().then_some(subcommand)
+    }
 }
 
 pub fn parse_args(config: BinaryConfig) -> Args {
-    let mut args = Args { input: None };
+    let mut positional = vec![];
+    let mut json = false;
+    let mut output = None;
+    let mut set_money = None;
+    let mut set_name = None;
+    let mut in_place = false;
+    let mut validate = false;
+    let mut dump_section = None;
     let mut env_args = std::env::args_os().skip(1);
 
     while let Some(arg) = env_args.next() {
-        if arg.as_encoded_bytes().starts_with(&[b'-']) {
+        if arg.as_encoded_bytes().starts_with(b"-") {
             let arg = arg.into_string().unwrap_or_else(|_| config.bail(1));
             if arg.starts_with("--") {
                 match arg.as_str() {
                     "--help" => {
                         config.bail(0);
                     }
+                    "--json" => json = true,
+                    "--output" => {
+                        output = Some(env_args.next().unwrap_or_else(|| config.bail(1)).into())
+                    }
+                    "--set-money" => {
+                        set_money = env_args
+                            .next()
+                            .and_then(|value| value.into_string().ok()?.parse().ok());
+                        if set_money.is_none() {
+                            config.bail(1);
+                        }
+                    }
+                    "--set-name" => {
+                        set_name = env_args.next().and_then(|name| name.into_string().ok());
+                        if set_name.is_none() {
+                            config.bail(1);
+                        }
+                    }
+                    "--in-place" => in_place = true,
+                    "--validate" => validate = true,
+                    "--dump-section" => {
+                        dump_section = env_args
+                            .next()
+                            .and_then(|section| section.into_string().ok()?.parse().ok());
+                        if dump_section.is_none() {
+                            config.bail(1);
+                        }
+                    }
                     _ => config.bail(1),
                 }
             } else {
@@ -44,12 +146,46 @@ pub fn parse_args(config: BinaryConfig) -> Args {
                 }
             }
         } else {
-            if args.input.is_some() {
-                config.bail(1);
-            }
-            args.input = Some(arg.into());
+            positional.push(arg);
         }
     }
 
-    args
+    let mut positional = positional.into_iter();
+    let first = positional.next();
+    let command = first
+        .as_ref()
+        .and_then(|arg| arg.to_str())
+        .filter(|arg| config.commands.contains(arg))
+        .map(str::to_string);
+    let Some(command) = command else {
+        if positional.next().is_some() {
+            config.bail(1);
+        }
+        return Args {
+            subcommand: None,
+            input: first.map(PathBuf::from),
+            json,
+            output,
+            set_money,
+            set_name,
+            in_place,
+            validate,
+            dump_section,
+        };
+    };
+
+    let input = positional.next().map(PathBuf::from);
+    let subcommand =
+        Subcommand::parse(&command, positional.collect()).unwrap_or_else(|| config.bail(1));
+    Args {
+        subcommand: Some(subcommand),
+        input,
+        json,
+        output,
+        set_money,
+        set_name,
+        in_place,
+        validate,
+        dump_section,
+    }
 }